@@ -0,0 +1,1594 @@
+// Bindings for the parts of phonon.h (Steam Audio 4.5) used by this crate, only
+// used with the mock feature, so that the crate can be built and tested without
+// the Steam Audio SDK. Types are laid out as generated by bindgen, while the
+// functions are stubs which do nothing, creating objects succeeds with null
+// handles. Keep in sync with phonon.h when upgrading Steam Audio.
+
+pub const STEAMAUDIO_VERSION_MAJOR: u32 = 4;
+pub const STEAMAUDIO_VERSION_MINOR: u32 = 5;
+pub const STEAMAUDIO_VERSION_PATCH: u32 = 3;
+
+pub type IPLint32 = ::std::os::raw::c_int;
+pub type IPLuint8 = ::std::os::raw::c_uchar;
+pub type IPLuint32 = ::std::os::raw::c_uint;
+pub type IPLfloat32 = f32;
+pub type IPLbyte = ::std::os::raw::c_uchar;
+pub type IPLsize = usize;
+pub type IPLstring = *mut ::std::os::raw::c_char;
+
+pub const IPLbool_IPL_FALSE: IPLbool = 0;
+pub const IPLbool_IPL_TRUE: IPLbool = 1;
+pub type IPLbool = ::std::os::raw::c_uint;
+
+pub const IPLerror_IPL_STATUS_SUCCESS: IPLerror = 0;
+pub const IPLerror_IPL_STATUS_FAILURE: IPLerror = 1;
+pub const IPLerror_IPL_STATUS_OUTOFMEMORY: IPLerror = 2;
+pub const IPLerror_IPL_STATUS_INITIALIZATION: IPLerror = 3;
+pub type IPLerror = ::std::os::raw::c_uint;
+
+// Context
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLContext_t {
+    _unused: [u8; 0],
+}
+pub type IPLContext = *mut _IPLContext_t;
+
+pub const IPLLogLevel_IPL_LOGLEVEL_INFO: IPLLogLevel = 0;
+pub const IPLLogLevel_IPL_LOGLEVEL_WARNING: IPLLogLevel = 1;
+pub const IPLLogLevel_IPL_LOGLEVEL_ERROR: IPLLogLevel = 2;
+pub const IPLLogLevel_IPL_LOGLEVEL_DEBUG: IPLLogLevel = 3;
+pub type IPLLogLevel = ::std::os::raw::c_uint;
+
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_SSE2: IPLSIMDLevel = 0;
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_SSE4: IPLSIMDLevel = 1;
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_AVX: IPLSIMDLevel = 2;
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_AVX2: IPLSIMDLevel = 3;
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_AVX512: IPLSIMDLevel = 4;
+pub const IPLSIMDLevel_IPL_SIMDLEVEL_NEON: IPLSIMDLevel = 0;
+pub type IPLSIMDLevel = ::std::os::raw::c_uint;
+
+pub const IPLContextFlags_IPL_CONTEXTFLAGS_VALIDATION: IPLContextFlags = 1;
+pub type IPLContextFlags = ::std::os::raw::c_uint;
+
+pub type IPLLogFunction = ::std::option::Option<
+    unsafe extern "C" fn(level: IPLLogLevel, message: *const ::std::os::raw::c_char),
+>;
+pub type IPLAllocateFunction = ::std::option::Option<
+    unsafe extern "C" fn(size: IPLsize, alignment: IPLsize) -> *mut ::std::os::raw::c_void,
+>;
+pub type IPLFreeFunction =
+    ::std::option::Option<unsafe extern "C" fn(memoryBlock: *mut ::std::os::raw::c_void)>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLContextSettings {
+    pub version: IPLuint32,
+    pub logCallback: IPLLogFunction,
+    pub allocateCallback: IPLAllocateFunction,
+    pub freeCallback: IPLFreeFunction,
+    pub simdLevel: IPLSIMDLevel,
+    pub flags: IPLContextFlags,
+}
+
+// Geometry
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLVector3 {
+    pub x: IPLfloat32,
+    pub y: IPLfloat32,
+    pub z: IPLfloat32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLMatrix4x4 {
+    pub elements: [[IPLfloat32; 4usize]; 4usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLBox {
+    pub minCoordinates: IPLVector3,
+    pub maxCoordinates: IPLVector3,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSphere {
+    pub center: IPLVector3,
+    pub radius: IPLfloat32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLCoordinateSpace3 {
+    pub right: IPLVector3,
+    pub up: IPLVector3,
+    pub ahead: IPLVector3,
+    pub origin: IPLVector3,
+}
+
+// Serialization
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLSerializedObject_t {
+    _unused: [u8; 0],
+}
+pub type IPLSerializedObject = *mut _IPLSerializedObject_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSerializedObjectSettings {
+    pub data: *mut IPLbyte,
+    pub size: IPLsize,
+}
+
+// OpenCL, Radeon Rays and TrueAudio Next
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLOpenCLDeviceList_t {
+    _unused: [u8; 0],
+}
+pub type IPLOpenCLDeviceList = *mut _IPLOpenCLDeviceList_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLOpenCLDevice_t {
+    _unused: [u8; 0],
+}
+pub type IPLOpenCLDevice = *mut _IPLOpenCLDevice_t;
+
+pub const IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_ANY: IPLOpenCLDeviceType = 0;
+pub const IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_CPU: IPLOpenCLDeviceType = 1;
+pub const IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_GPU: IPLOpenCLDeviceType = 2;
+pub type IPLOpenCLDeviceType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLOpenCLDeviceSettings {
+    pub type_: IPLOpenCLDeviceType,
+    pub numCUsToReserve: IPLint32,
+    pub fractionCUsForIRUpdate: IPLfloat32,
+    pub requiresTAN: IPLbool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLOpenCLDeviceDesc {
+    pub platform: *mut ::std::os::raw::c_void,
+    pub platformName: *const ::std::os::raw::c_char,
+    pub platformVendor: *const ::std::os::raw::c_char,
+    pub platformVersion: *const ::std::os::raw::c_char,
+    pub device: *mut ::std::os::raw::c_void,
+    pub deviceName: *const ::std::os::raw::c_char,
+    pub deviceVendor: *const ::std::os::raw::c_char,
+    pub deviceVersion: *const ::std::os::raw::c_char,
+    pub type_: IPLOpenCLDeviceType,
+    pub numConvolutionCUs: IPLint32,
+    pub numIRUpdateCUs: IPLint32,
+    pub granularity: IPLint32,
+    pub perfScore: IPLfloat32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLRadeonRaysDevice_t {
+    _unused: [u8; 0],
+}
+pub type IPLRadeonRaysDevice = *mut _IPLRadeonRaysDevice_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLRadeonRaysDeviceSettings {
+    pub reserved: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLTrueAudioNextDevice_t {
+    _unused: [u8; 0],
+}
+pub type IPLTrueAudioNextDevice = *mut _IPLTrueAudioNextDevice_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLTrueAudioNextDeviceSettings {
+    pub frameSize: IPLint32,
+    pub irSize: IPLint32,
+    pub order: IPLint32,
+    pub maxSources: IPLint32,
+}
+
+// Scene
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLEmbreeDevice_t {
+    _unused: [u8; 0],
+}
+pub type IPLEmbreeDevice = *mut _IPLEmbreeDevice_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLScene_t {
+    _unused: [u8; 0],
+}
+pub type IPLScene = *mut _IPLScene_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLStaticMesh_t {
+    _unused: [u8; 0],
+}
+pub type IPLStaticMesh = *mut _IPLStaticMesh_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLInstancedMesh_t {
+    _unused: [u8; 0],
+}
+pub type IPLInstancedMesh = *mut _IPLInstancedMesh_t;
+
+pub const IPLSceneType_IPL_SCENETYPE_DEFAULT: IPLSceneType = 0;
+pub const IPLSceneType_IPL_SCENETYPE_EMBREE: IPLSceneType = 1;
+pub const IPLSceneType_IPL_SCENETYPE_RADEONRAYS: IPLSceneType = 2;
+pub const IPLSceneType_IPL_SCENETYPE_CUSTOM: IPLSceneType = 3;
+pub type IPLSceneType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLTriangle {
+    pub indices: [IPLint32; 3usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLMaterial {
+    pub absorption: [IPLfloat32; 3usize],
+    pub scattering: IPLfloat32,
+    pub transmission: [IPLfloat32; 3usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLRay {
+    pub origin: IPLVector3,
+    pub direction: IPLVector3,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLHit {
+    pub distance: IPLfloat32,
+    pub triangleIndex: IPLint32,
+    pub objectIndex: IPLint32,
+    pub materialIndex: IPLint32,
+    pub normal: IPLVector3,
+    pub material: *mut IPLMaterial,
+}
+
+pub type IPLClosestHitCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        ray: *const IPLRay,
+        minDistance: IPLfloat32,
+        maxDistance: IPLfloat32,
+        hit: *mut IPLHit,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type IPLAnyHitCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        ray: *const IPLRay,
+        minDistance: IPLfloat32,
+        maxDistance: IPLfloat32,
+        occluded: *mut IPLuint8,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type IPLBatchedClosestHitCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        numRays: IPLint32,
+        rays: *const IPLRay,
+        minDistances: *const IPLfloat32,
+        maxDistances: *const IPLfloat32,
+        hits: *mut IPLHit,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type IPLBatchedAnyHitCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        numRays: IPLint32,
+        rays: *const IPLRay,
+        minDistances: *const IPLfloat32,
+        maxDistances: *const IPLfloat32,
+        occluded: *mut IPLuint8,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSceneSettings {
+    pub type_: IPLSceneType,
+    pub closestHitCallback: IPLClosestHitCallback,
+    pub anyHitCallback: IPLAnyHitCallback,
+    pub batchedClosestHitCallback: IPLBatchedClosestHitCallback,
+    pub batchedAnyHitCallback: IPLBatchedAnyHitCallback,
+    pub userData: *mut ::std::os::raw::c_void,
+    pub embreeDevice: IPLEmbreeDevice,
+    pub radeonRaysDevice: IPLRadeonRaysDevice,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLStaticMeshSettings {
+    pub numVertices: IPLint32,
+    pub numTriangles: IPLint32,
+    pub numMaterials: IPLint32,
+    pub vertices: *mut IPLVector3,
+    pub triangles: *mut IPLTriangle,
+    pub materialIndices: *mut IPLint32,
+    pub materials: *mut IPLMaterial,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLInstancedMeshSettings {
+    pub subScene: IPLScene,
+    pub transform: IPLMatrix4x4,
+}
+
+// Audio buffers
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAudioSettings {
+    pub samplingRate: IPLint32,
+    pub frameSize: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAudioBuffer {
+    pub numChannels: IPLint32,
+    pub numSamples: IPLint32,
+    pub data: *mut *mut IPLfloat32,
+}
+
+pub const IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILREMAINING: IPLAudioEffectState = 0;
+pub const IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE: IPLAudioEffectState = 1;
+pub type IPLAudioEffectState = ::std::os::raw::c_uint;
+
+// HRTF
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLHRTF_t {
+    _unused: [u8; 0],
+}
+pub type IPLHRTF = *mut _IPLHRTF_t;
+
+pub const IPLHRTFType_IPL_HRTFTYPE_DEFAULT: IPLHRTFType = 0;
+pub const IPLHRTFType_IPL_HRTFTYPE_SOFA: IPLHRTFType = 1;
+pub type IPLHRTFType = ::std::os::raw::c_uint;
+
+pub const IPLHRTFNormType_IPL_HRTFNORMTYPE_NONE: IPLHRTFNormType = 0;
+pub const IPLHRTFNormType_IPL_HRTFNORMTYPE_RMS: IPLHRTFNormType = 1;
+pub type IPLHRTFNormType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLHRTFSettings {
+    pub type_: IPLHRTFType,
+    pub sofaFileName: *const ::std::os::raw::c_char,
+    pub sofaData: *const IPLuint8,
+    pub sofaDataSize: ::std::os::raw::c_int,
+    pub volume: IPLfloat32,
+    pub normType: IPLHRTFNormType,
+}
+
+pub const IPLHRTFInterpolation_IPL_HRTFINTERPOLATION_NEAREST: IPLHRTFInterpolation = 0;
+pub const IPLHRTFInterpolation_IPL_HRTFINTERPOLATION_BILINEAR: IPLHRTFInterpolation = 1;
+pub type IPLHRTFInterpolation = ::std::os::raw::c_uint;
+
+// Effects
+
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_MONO: IPLSpeakerLayoutType = 0;
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_STEREO: IPLSpeakerLayoutType = 1;
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_QUADRAPHONIC: IPLSpeakerLayoutType = 2;
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_SURROUND_5_1: IPLSpeakerLayoutType = 3;
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_SURROUND_7_1: IPLSpeakerLayoutType = 4;
+pub const IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_CUSTOM: IPLSpeakerLayoutType = 5;
+pub type IPLSpeakerLayoutType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSpeakerLayout {
+    pub type_: IPLSpeakerLayoutType,
+    pub numSpeakers: IPLint32,
+    pub speakers: *mut IPLVector3,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLPanningEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLPanningEffect = *mut _IPLPanningEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLPanningEffectSettings {
+    pub speakerLayout: IPLSpeakerLayout,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLPanningEffectParams {
+    pub direction: IPLVector3,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLBinauralEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLBinauralEffect = *mut _IPLBinauralEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLBinauralEffectSettings {
+    pub hrtf: IPLHRTF,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLBinauralEffectParams {
+    pub direction: IPLVector3,
+    pub interpolation: IPLHRTFInterpolation,
+    pub spatialBlend: IPLfloat32,
+    pub hrtf: IPLHRTF,
+    pub peakDelays: *mut IPLfloat32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLVirtualSurroundEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLVirtualSurroundEffect = *mut _IPLVirtualSurroundEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLVirtualSurroundEffectSettings {
+    pub speakerLayout: IPLSpeakerLayout,
+    pub hrtf: IPLHRTF,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLVirtualSurroundEffectParams {
+    pub hrtf: IPLHRTF,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLAmbisonicsEncodeEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLAmbisonicsEncodeEffect = *mut _IPLAmbisonicsEncodeEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsEncodeEffectSettings {
+    pub maxOrder: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsEncodeEffectParams {
+    pub direction: IPLVector3,
+    pub order: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLAmbisonicsPanningEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLAmbisonicsPanningEffect = *mut _IPLAmbisonicsPanningEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsPanningEffectSettings {
+    pub speakerLayout: IPLSpeakerLayout,
+    pub maxOrder: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsPanningEffectParams {
+    pub order: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLAmbisonicsBinauralEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLAmbisonicsBinauralEffect = *mut _IPLAmbisonicsBinauralEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsBinauralEffectSettings {
+    pub hrtf: IPLHRTF,
+    pub maxOrder: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsBinauralEffectParams {
+    pub hrtf: IPLHRTF,
+    pub order: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLAmbisonicsRotationEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLAmbisonicsRotationEffect = *mut _IPLAmbisonicsRotationEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsRotationEffectSettings {
+    pub maxOrder: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsRotationEffectParams {
+    pub orientation: IPLCoordinateSpace3,
+    pub order: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLAmbisonicsDecodeEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLAmbisonicsDecodeEffect = *mut _IPLAmbisonicsDecodeEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsDecodeEffectSettings {
+    pub speakerLayout: IPLSpeakerLayout,
+    pub hrtf: IPLHRTF,
+    pub maxOrder: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAmbisonicsDecodeEffectParams {
+    pub order: IPLint32,
+    pub hrtf: IPLHRTF,
+    pub orientation: IPLCoordinateSpace3,
+    pub binaural: IPLbool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLDirectEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLDirectEffect = *mut _IPLDirectEffect_t;
+
+pub const IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYDISTANCEATTENUATION:
+    IPLDirectEffectFlags = 1;
+pub const IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYAIRABSORPTION: IPLDirectEffectFlags = 2;
+pub const IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYDIRECTIVITY: IPLDirectEffectFlags = 4;
+pub const IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYOCCLUSION: IPLDirectEffectFlags = 8;
+pub const IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYTRANSMISSION: IPLDirectEffectFlags = 16;
+pub type IPLDirectEffectFlags = ::std::os::raw::c_uint;
+
+pub const IPLTransmissionType_IPL_TRANSMISSIONTYPE_FREQINDEPENDENT: IPLTransmissionType = 0;
+pub const IPLTransmissionType_IPL_TRANSMISSIONTYPE_FREQDEPENDENT: IPLTransmissionType = 1;
+pub type IPLTransmissionType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLDirectEffectSettings {
+    pub numChannels: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLDirectEffectParams {
+    pub flags: IPLDirectEffectFlags,
+    pub transmissionType: IPLTransmissionType,
+    pub distanceAttenuation: IPLfloat32,
+    pub airAbsorption: [IPLfloat32; 3usize],
+    pub directivity: IPLfloat32,
+    pub occlusion: IPLfloat32,
+    pub transmission: [IPLfloat32; 3usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLReflectionEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLReflectionEffect = *mut _IPLReflectionEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLReflectionEffectIR_t {
+    _unused: [u8; 0],
+}
+pub type IPLReflectionEffectIR = *mut _IPLReflectionEffectIR_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLReflectionMixer_t {
+    _unused: [u8; 0],
+}
+pub type IPLReflectionMixer = *mut _IPLReflectionMixer_t;
+
+pub const IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_CONVOLUTION: IPLReflectionEffectType = 0;
+pub const IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_PARAMETRIC: IPLReflectionEffectType = 1;
+pub const IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_HYBRID: IPLReflectionEffectType = 2;
+pub const IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_TAN: IPLReflectionEffectType = 3;
+pub type IPLReflectionEffectType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLReflectionEffectSettings {
+    pub type_: IPLReflectionEffectType,
+    pub irSize: IPLint32,
+    pub numChannels: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLReflectionEffectParams {
+    pub type_: IPLReflectionEffectType,
+    pub ir: IPLReflectionEffectIR,
+    pub reverbTimes: [IPLfloat32; 3usize],
+    pub eq: [IPLfloat32; 3usize],
+    pub delay: IPLint32,
+    pub numChannels: IPLint32,
+    pub irSize: IPLint32,
+    pub tanDevice: IPLTrueAudioNextDevice,
+    pub tanSlot: IPLint32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLPathEffect_t {
+    _unused: [u8; 0],
+}
+pub type IPLPathEffect = *mut _IPLPathEffect_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLPathEffectSettings {
+    pub maxOrder: IPLint32,
+    pub spatialize: IPLbool,
+    pub speakerLayout: IPLSpeakerLayout,
+    pub hrtf: IPLHRTF,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLPathEffectParams {
+    pub eqCoeffs: [IPLfloat32; 3usize],
+    pub shCoeffs: *mut IPLfloat32,
+    pub order: IPLint32,
+    pub binaural: IPLbool,
+    pub hrtf: IPLHRTF,
+    pub listener: IPLCoordinateSpace3,
+}
+
+// Probes
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLProbeArray_t {
+    _unused: [u8; 0],
+}
+pub type IPLProbeArray = *mut _IPLProbeArray_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLProbeBatch_t {
+    _unused: [u8; 0],
+}
+pub type IPLProbeBatch = *mut _IPLProbeBatch_t;
+
+pub const IPLProbeGenerationType_IPL_PROBEGENERATIONTYPE_CENTROID: IPLProbeGenerationType = 0;
+pub const IPLProbeGenerationType_IPL_PROBEGENERATIONTYPE_UNIFORMFLOOR: IPLProbeGenerationType = 1;
+pub type IPLProbeGenerationType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLProbeGenerationParams {
+    pub type_: IPLProbeGenerationType,
+    pub spacing: IPLfloat32,
+    pub height: IPLfloat32,
+    pub transform: IPLMatrix4x4,
+}
+
+pub const IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_REVERB: IPLBakedDataVariation = 0;
+pub const IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_STATICSOURCE: IPLBakedDataVariation = 1;
+pub const IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_STATICLISTENER: IPLBakedDataVariation = 2;
+pub const IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_DYNAMIC: IPLBakedDataVariation = 3;
+pub type IPLBakedDataVariation = ::std::os::raw::c_uint;
+
+pub const IPLBakedDataType_IPL_BAKEDDATATYPE_REFLECTIONS: IPLBakedDataType = 0;
+pub const IPLBakedDataType_IPL_BAKEDDATATYPE_PATHING: IPLBakedDataType = 1;
+pub type IPLBakedDataType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLBakedDataIdentifier {
+    pub type_: IPLBakedDataType,
+    pub variation: IPLBakedDataVariation,
+    pub endpointInfluence: IPLSphere,
+}
+
+// Simulation
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLSimulator_t {
+    _unused: [u8; 0],
+}
+pub type IPLSimulator = *mut _IPLSimulator_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IPLSource_t {
+    _unused: [u8; 0],
+}
+pub type IPLSource = *mut _IPLSource_t;
+
+pub const IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT: IPLSimulationFlags = 1;
+pub const IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS: IPLSimulationFlags = 2;
+pub const IPLSimulationFlags_IPL_SIMULATIONFLAGS_PATHING: IPLSimulationFlags = 4;
+pub type IPLSimulationFlags = ::std::os::raw::c_uint;
+
+pub const IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DISTANCEATTENUATION:
+    IPLDirectSimulationFlags = 1;
+pub const IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_AIRABSORPTION:
+    IPLDirectSimulationFlags = 2;
+pub const IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DIRECTIVITY: IPLDirectSimulationFlags =
+    4;
+pub const IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_OCCLUSION: IPLDirectSimulationFlags =
+    8;
+pub const IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_TRANSMISSION:
+    IPLDirectSimulationFlags = 16;
+pub type IPLDirectSimulationFlags = ::std::os::raw::c_uint;
+
+pub const IPLDistanceAttenuationModelType_IPL_DISTANCEATTENUATIONTYPE_DEFAULT:
+    IPLDistanceAttenuationModelType = 0;
+pub const IPLDistanceAttenuationModelType_IPL_DISTANCEATTENUATIONTYPE_INVERSEDISTANCE:
+    IPLDistanceAttenuationModelType = 1;
+pub const IPLDistanceAttenuationModelType_IPL_DISTANCEATTENUATIONTYPE_CALLBACK:
+    IPLDistanceAttenuationModelType = 2;
+pub type IPLDistanceAttenuationModelType = ::std::os::raw::c_uint;
+
+pub type IPLDistanceAttenuationCallback = ::std::option::Option<
+    unsafe extern "C" fn(distance: IPLfloat32, userData: *mut ::std::os::raw::c_void) -> IPLfloat32,
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLDistanceAttenuationModel {
+    pub type_: IPLDistanceAttenuationModelType,
+    pub minDistance: IPLfloat32,
+    pub callback: IPLDistanceAttenuationCallback,
+    pub userData: *mut ::std::os::raw::c_void,
+    pub dirty: IPLbool,
+}
+
+pub const IPLAirAbsorptionModelType_IPL_AIRABSORPTIONTYPE_DEFAULT: IPLAirAbsorptionModelType = 0;
+pub const IPLAirAbsorptionModelType_IPL_AIRABSORPTIONTYPE_EXPONENTIAL: IPLAirAbsorptionModelType =
+    1;
+pub const IPLAirAbsorptionModelType_IPL_AIRABSORPTIONTYPE_CALLBACK: IPLAirAbsorptionModelType = 2;
+pub type IPLAirAbsorptionModelType = ::std::os::raw::c_uint;
+
+pub type IPLAirAbsorptionCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        distance: IPLfloat32,
+        band: IPLint32,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> IPLfloat32,
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLAirAbsorptionModel {
+    pub type_: IPLAirAbsorptionModelType,
+    pub coefficients: [IPLfloat32; 3usize],
+    pub callback: IPLAirAbsorptionCallback,
+    pub userData: *mut ::std::os::raw::c_void,
+    pub dirty: IPLbool,
+}
+
+pub type IPLDirectivityCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        direction: IPLVector3,
+        userData: *mut ::std::os::raw::c_void,
+    ) -> IPLfloat32,
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLDirectivity {
+    pub dipoleWeight: IPLfloat32,
+    pub dipolePower: IPLfloat32,
+    pub callback: IPLDirectivityCallback,
+    pub userData: *mut ::std::os::raw::c_void,
+}
+
+pub const IPLOcclusionType_IPL_OCCLUSIONTYPE_RAYCAST: IPLOcclusionType = 0;
+pub const IPLOcclusionType_IPL_OCCLUSIONTYPE_VOLUMETRIC: IPLOcclusionType = 1;
+pub type IPLOcclusionType = ::std::os::raw::c_uint;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSimulationSettings {
+    pub flags: IPLSimulationFlags,
+    pub sceneType: IPLSceneType,
+    pub reflectionType: IPLReflectionEffectType,
+    pub maxNumOcclusionSamples: IPLint32,
+    pub maxNumRays: IPLint32,
+    pub numDiffuseSamples: IPLint32,
+    pub maxDuration: IPLfloat32,
+    pub maxOrder: IPLint32,
+    pub maxNumSources: IPLint32,
+    pub numThreads: IPLint32,
+    pub rayBatchSize: IPLint32,
+    pub numVisSamples: IPLint32,
+    pub samplingRate: IPLint32,
+    pub frameSize: IPLint32,
+    pub openCLDevice: IPLOpenCLDevice,
+    pub radeonRaysDevice: IPLRadeonRaysDevice,
+    pub tanDevice: IPLTrueAudioNextDevice,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSourceSettings {
+    pub flags: IPLSimulationFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSimulationInputs {
+    pub flags: IPLSimulationFlags,
+    pub directFlags: IPLDirectSimulationFlags,
+    pub source: IPLCoordinateSpace3,
+    pub distanceAttenuationModel: IPLDistanceAttenuationModel,
+    pub airAbsorptionModel: IPLAirAbsorptionModel,
+    pub directivity: IPLDirectivity,
+    pub occlusionType: IPLOcclusionType,
+    pub occlusionRadius: IPLfloat32,
+    pub numOcclusionSamples: IPLint32,
+    pub reverbScale: [IPLfloat32; 3usize],
+    pub hybridReverbTransitionTime: IPLfloat32,
+    pub hybridReverbOverlapPercent: IPLfloat32,
+    pub baked: IPLbool,
+    pub bakedDataIdentifier: IPLBakedDataIdentifier,
+    pub pathingProbes: IPLProbeBatch,
+    pub visRadius: IPLfloat32,
+    pub visThreshold: IPLfloat32,
+    pub visRange: IPLfloat32,
+    pub pathingOrder: IPLint32,
+    pub enableValidation: IPLbool,
+    pub findAlternatePaths: IPLbool,
+    pub numTransmissionRays: IPLint32,
+}
+
+pub type IPLPathingVisualizationCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        from: IPLVector3,
+        to: IPLVector3,
+        occluded: IPLbool,
+        userData: *mut ::std::os::raw::c_void,
+    ),
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSimulationSharedInputs {
+    pub listener: IPLCoordinateSpace3,
+    pub numRays: IPLint32,
+    pub numBounces: IPLint32,
+    pub duration: IPLfloat32,
+    pub order: IPLint32,
+    pub irradianceMinDistance: IPLfloat32,
+    pub pathingVisCallback: IPLPathingVisualizationCallback,
+    pub pathingUserData: *mut ::std::os::raw::c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IPLSimulationOutputs {
+    pub direct: IPLDirectEffectParams,
+    pub reflections: IPLReflectionEffectParams,
+    pub pathing: IPLPathEffectParams,
+}
+
+pub unsafe extern "C" fn iplContextCreate(
+    _settings: *mut IPLContextSettings,
+    _context: *mut IPLContext,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplContextRetain(context: IPLContext) -> IPLContext {
+    context
+}
+
+pub unsafe extern "C" fn iplContextRelease(_context: *mut IPLContext) {}
+
+pub unsafe extern "C" fn iplCalculateRelativeDirection(
+    _context: IPLContext,
+    _sourcePosition: IPLVector3,
+    _listenerPosition: IPLVector3,
+    _listenerAhead: IPLVector3,
+    _listenerUp: IPLVector3,
+) -> IPLVector3 {
+    IPLVector3 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    }
+}
+
+pub unsafe extern "C" fn iplSerializedObjectCreate(
+    _context: IPLContext,
+    _settings: *mut IPLSerializedObjectSettings,
+    _serializedObject: *mut IPLSerializedObject,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplSerializedObjectRetain(
+    serializedObject: IPLSerializedObject,
+) -> IPLSerializedObject {
+    serializedObject
+}
+
+pub unsafe extern "C" fn iplSerializedObjectRelease(_serializedObject: *mut IPLSerializedObject) {}
+
+pub unsafe extern "C" fn iplSerializedObjectGetSize(
+    _serializedObject: IPLSerializedObject,
+) -> IPLsize {
+    0
+}
+
+pub unsafe extern "C" fn iplSerializedObjectGetData(
+    _serializedObject: IPLSerializedObject,
+) -> *mut IPLbyte {
+    ::std::ptr::null_mut()
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceListCreate(
+    _context: IPLContext,
+    _settings: *mut IPLOpenCLDeviceSettings,
+    _deviceList: *mut IPLOpenCLDeviceList,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceListRetain(
+    deviceList: IPLOpenCLDeviceList,
+) -> IPLOpenCLDeviceList {
+    deviceList
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceListRelease(_deviceList: *mut IPLOpenCLDeviceList) {}
+
+pub unsafe extern "C" fn iplOpenCLDeviceListGetNumDevices(
+    _deviceList: IPLOpenCLDeviceList,
+) -> IPLint32 {
+    0
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceListGetDeviceDesc(
+    _deviceList: IPLOpenCLDeviceList,
+    _index: IPLint32,
+    _deviceDesc: *mut IPLOpenCLDeviceDesc,
+) {
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceCreate(
+    _context: IPLContext,
+    _deviceList: IPLOpenCLDeviceList,
+    _index: IPLint32,
+    _device: *mut IPLOpenCLDevice,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceRetain(device: IPLOpenCLDevice) -> IPLOpenCLDevice {
+    device
+}
+
+pub unsafe extern "C" fn iplOpenCLDeviceRelease(_device: *mut IPLOpenCLDevice) {}
+
+pub unsafe extern "C" fn iplRadeonRaysDeviceCreate(
+    _openCLDevice: IPLOpenCLDevice,
+    _settings: *mut IPLRadeonRaysDeviceSettings,
+    _rrDevice: *mut IPLRadeonRaysDevice,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplRadeonRaysDeviceRetain(
+    device: IPLRadeonRaysDevice,
+) -> IPLRadeonRaysDevice {
+    device
+}
+
+pub unsafe extern "C" fn iplRadeonRaysDeviceRelease(_device: *mut IPLRadeonRaysDevice) {}
+
+pub unsafe extern "C" fn iplTrueAudioNextDeviceCreate(
+    _openCLDevice: IPLOpenCLDevice,
+    _settings: *mut IPLTrueAudioNextDeviceSettings,
+    _tanDevice: *mut IPLTrueAudioNextDevice,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplTrueAudioNextDeviceRetain(
+    device: IPLTrueAudioNextDevice,
+) -> IPLTrueAudioNextDevice {
+    device
+}
+
+pub unsafe extern "C" fn iplTrueAudioNextDeviceRelease(_device: *mut IPLTrueAudioNextDevice) {}
+
+pub unsafe extern "C" fn iplSceneCreate(
+    _context: IPLContext,
+    _settings: *mut IPLSceneSettings,
+    _scene: *mut IPLScene,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplSceneRetain(scene: IPLScene) -> IPLScene {
+    scene
+}
+
+pub unsafe extern "C" fn iplSceneRelease(_scene: *mut IPLScene) {}
+
+pub unsafe extern "C" fn iplSceneCommit(_scene: IPLScene) {}
+
+pub unsafe extern "C" fn iplStaticMeshCreate(
+    _scene: IPLScene,
+    _settings: *mut IPLStaticMeshSettings,
+    _staticMesh: *mut IPLStaticMesh,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplStaticMeshRetain(staticMesh: IPLStaticMesh) -> IPLStaticMesh {
+    staticMesh
+}
+
+pub unsafe extern "C" fn iplStaticMeshRelease(_staticMesh: *mut IPLStaticMesh) {}
+
+pub unsafe extern "C" fn iplStaticMeshAdd(_staticMesh: IPLStaticMesh, _scene: IPLScene) {}
+
+pub unsafe extern "C" fn iplStaticMeshRemove(_staticMesh: IPLStaticMesh, _scene: IPLScene) {}
+
+pub unsafe extern "C" fn iplInstancedMeshCreate(
+    _scene: IPLScene,
+    _settings: *mut IPLInstancedMeshSettings,
+    _instancedMesh: *mut IPLInstancedMesh,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplInstancedMeshRetain(
+    instancedMesh: IPLInstancedMesh,
+) -> IPLInstancedMesh {
+    instancedMesh
+}
+
+pub unsafe extern "C" fn iplInstancedMeshRelease(_instancedMesh: *mut IPLInstancedMesh) {}
+
+pub unsafe extern "C" fn iplInstancedMeshAdd(_instancedMesh: IPLInstancedMesh, _scene: IPLScene) {}
+
+pub unsafe extern "C" fn iplInstancedMeshRemove(
+    _instancedMesh: IPLInstancedMesh,
+    _scene: IPLScene,
+) {
+}
+
+pub unsafe extern "C" fn iplInstancedMeshUpdateTransform(
+    _instancedMesh: IPLInstancedMesh,
+    _scene: IPLScene,
+    _transform: IPLMatrix4x4,
+) {
+}
+
+pub unsafe extern "C" fn iplAudioBufferMix(
+    _context: IPLContext,
+    _in_: *mut IPLAudioBuffer,
+    _mix: *mut IPLAudioBuffer,
+) {
+}
+
+pub unsafe extern "C" fn iplHRTFCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _hrtfSettings: *mut IPLHRTFSettings,
+    _hrtf: *mut IPLHRTF,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplHRTFRetain(hrtf: IPLHRTF) -> IPLHRTF {
+    hrtf
+}
+
+pub unsafe extern "C" fn iplHRTFRelease(_hrtf: *mut IPLHRTF) {}
+
+pub unsafe extern "C" fn iplPanningEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLPanningEffectSettings,
+    _effect: *mut IPLPanningEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplPanningEffectRetain(effect: IPLPanningEffect) -> IPLPanningEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplPanningEffectRelease(_effect: *mut IPLPanningEffect) {}
+
+pub unsafe extern "C" fn iplPanningEffectReset(_effect: IPLPanningEffect) {}
+
+pub unsafe extern "C" fn iplPanningEffectApply(
+    _effect: IPLPanningEffect,
+    _params: *mut IPLPanningEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplBinauralEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLBinauralEffectSettings,
+    _effect: *mut IPLBinauralEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplBinauralEffectRetain(effect: IPLBinauralEffect) -> IPLBinauralEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplBinauralEffectRelease(_effect: *mut IPLBinauralEffect) {}
+
+pub unsafe extern "C" fn iplBinauralEffectReset(_effect: IPLBinauralEffect) {}
+
+pub unsafe extern "C" fn iplBinauralEffectApply(
+    _effect: IPLBinauralEffect,
+    _params: *mut IPLBinauralEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplVirtualSurroundEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLVirtualSurroundEffectSettings,
+    _effect: *mut IPLVirtualSurroundEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplVirtualSurroundEffectRetain(
+    effect: IPLVirtualSurroundEffect,
+) -> IPLVirtualSurroundEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplVirtualSurroundEffectRelease(_effect: *mut IPLVirtualSurroundEffect) {}
+
+pub unsafe extern "C" fn iplVirtualSurroundEffectReset(_effect: IPLVirtualSurroundEffect) {}
+
+pub unsafe extern "C" fn iplVirtualSurroundEffectApply(
+    _effect: IPLVirtualSurroundEffect,
+    _params: *mut IPLVirtualSurroundEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplAmbisonicsEncodeEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLAmbisonicsEncodeEffectSettings,
+    _effect: *mut IPLAmbisonicsEncodeEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplAmbisonicsEncodeEffectRetain(
+    effect: IPLAmbisonicsEncodeEffect,
+) -> IPLAmbisonicsEncodeEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplAmbisonicsEncodeEffectRelease(_effect: *mut IPLAmbisonicsEncodeEffect) {
+}
+
+pub unsafe extern "C" fn iplAmbisonicsEncodeEffectReset(_effect: IPLAmbisonicsEncodeEffect) {}
+
+pub unsafe extern "C" fn iplAmbisonicsEncodeEffectApply(
+    _effect: IPLAmbisonicsEncodeEffect,
+    _params: *mut IPLAmbisonicsEncodeEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplAmbisonicsPanningEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLAmbisonicsPanningEffectSettings,
+    _effect: *mut IPLAmbisonicsPanningEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplAmbisonicsPanningEffectRetain(
+    effect: IPLAmbisonicsPanningEffect,
+) -> IPLAmbisonicsPanningEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplAmbisonicsPanningEffectRelease(
+    _effect: *mut IPLAmbisonicsPanningEffect,
+) {
+}
+
+pub unsafe extern "C" fn iplAmbisonicsPanningEffectReset(_effect: IPLAmbisonicsPanningEffect) {}
+
+pub unsafe extern "C" fn iplAmbisonicsPanningEffectApply(
+    _effect: IPLAmbisonicsPanningEffect,
+    _params: *mut IPLAmbisonicsPanningEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplAmbisonicsBinauralEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLAmbisonicsBinauralEffectSettings,
+    _effect: *mut IPLAmbisonicsBinauralEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplAmbisonicsBinauralEffectRetain(
+    effect: IPLAmbisonicsBinauralEffect,
+) -> IPLAmbisonicsBinauralEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplAmbisonicsBinauralEffectRelease(
+    _effect: *mut IPLAmbisonicsBinauralEffect,
+) {
+}
+
+pub unsafe extern "C" fn iplAmbisonicsBinauralEffectReset(_effect: IPLAmbisonicsBinauralEffect) {}
+
+pub unsafe extern "C" fn iplAmbisonicsBinauralEffectApply(
+    _effect: IPLAmbisonicsBinauralEffect,
+    _params: *mut IPLAmbisonicsBinauralEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplAmbisonicsRotationEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLAmbisonicsRotationEffectSettings,
+    _effect: *mut IPLAmbisonicsRotationEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplAmbisonicsRotationEffectRetain(
+    effect: IPLAmbisonicsRotationEffect,
+) -> IPLAmbisonicsRotationEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplAmbisonicsRotationEffectRelease(
+    _effect: *mut IPLAmbisonicsRotationEffect,
+) {
+}
+
+pub unsafe extern "C" fn iplAmbisonicsRotationEffectReset(_effect: IPLAmbisonicsRotationEffect) {}
+
+pub unsafe extern "C" fn iplAmbisonicsRotationEffectApply(
+    _effect: IPLAmbisonicsRotationEffect,
+    _params: *mut IPLAmbisonicsRotationEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplAmbisonicsDecodeEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLAmbisonicsDecodeEffectSettings,
+    _effect: *mut IPLAmbisonicsDecodeEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplAmbisonicsDecodeEffectRetain(
+    effect: IPLAmbisonicsDecodeEffect,
+) -> IPLAmbisonicsDecodeEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplAmbisonicsDecodeEffectRelease(_effect: *mut IPLAmbisonicsDecodeEffect) {
+}
+
+pub unsafe extern "C" fn iplAmbisonicsDecodeEffectReset(_effect: IPLAmbisonicsDecodeEffect) {}
+
+pub unsafe extern "C" fn iplAmbisonicsDecodeEffectApply(
+    _effect: IPLAmbisonicsDecodeEffect,
+    _params: *mut IPLAmbisonicsDecodeEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplDirectEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLDirectEffectSettings,
+    _effect: *mut IPLDirectEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplDirectEffectRetain(effect: IPLDirectEffect) -> IPLDirectEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplDirectEffectRelease(_effect: *mut IPLDirectEffect) {}
+
+pub unsafe extern "C" fn iplDirectEffectReset(_effect: IPLDirectEffect) {}
+
+pub unsafe extern "C" fn iplDirectEffectApply(
+    _effect: IPLDirectEffect,
+    _params: *mut IPLDirectEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplReflectionEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLReflectionEffectSettings,
+    _effect: *mut IPLReflectionEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplReflectionEffectRetain(
+    effect: IPLReflectionEffect,
+) -> IPLReflectionEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplReflectionEffectRelease(_effect: *mut IPLReflectionEffect) {}
+
+pub unsafe extern "C" fn iplReflectionEffectReset(_effect: IPLReflectionEffect) {}
+
+pub unsafe extern "C" fn iplReflectionEffectApply(
+    _effect: IPLReflectionEffect,
+    _params: *mut IPLReflectionEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+    _mixer: IPLReflectionMixer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplPathEffectCreate(
+    _context: IPLContext,
+    _audioSettings: *mut IPLAudioSettings,
+    _effectSettings: *mut IPLPathEffectSettings,
+    _effect: *mut IPLPathEffect,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplPathEffectRetain(effect: IPLPathEffect) -> IPLPathEffect {
+    effect
+}
+
+pub unsafe extern "C" fn iplPathEffectRelease(_effect: *mut IPLPathEffect) {}
+
+pub unsafe extern "C" fn iplPathEffectReset(_effect: IPLPathEffect) {}
+
+pub unsafe extern "C" fn iplPathEffectApply(
+    _effect: IPLPathEffect,
+    _params: *mut IPLPathEffectParams,
+    _in_: *mut IPLAudioBuffer,
+    _out: *mut IPLAudioBuffer,
+) -> IPLAudioEffectState {
+    IPLAudioEffectState_IPL_AUDIOEFFECTSTATE_TAILCOMPLETE
+}
+
+pub unsafe extern "C" fn iplProbeArrayCreate(
+    _context: IPLContext,
+    _probeArray: *mut IPLProbeArray,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplProbeArrayRetain(probeArray: IPLProbeArray) -> IPLProbeArray {
+    probeArray
+}
+
+pub unsafe extern "C" fn iplProbeArrayRelease(_probeArray: *mut IPLProbeArray) {}
+
+pub unsafe extern "C" fn iplProbeArrayGenerateProbes(
+    _probeArray: IPLProbeArray,
+    _scene: IPLScene,
+    _params: *mut IPLProbeGenerationParams,
+) {
+}
+
+pub unsafe extern "C" fn iplProbeArrayGetNumProbes(_probeArray: IPLProbeArray) -> IPLint32 {
+    0
+}
+
+pub unsafe extern "C" fn iplProbeBatchCreate(
+    _context: IPLContext,
+    _probeBatch: *mut IPLProbeBatch,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplProbeBatchRetain(probeBatch: IPLProbeBatch) -> IPLProbeBatch {
+    probeBatch
+}
+
+pub unsafe extern "C" fn iplProbeBatchRelease(_probeBatch: *mut IPLProbeBatch) {}
+
+pub unsafe extern "C" fn iplProbeBatchLoad(
+    _context: IPLContext,
+    _serializedObject: IPLSerializedObject,
+    _probeBatch: *mut IPLProbeBatch,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplProbeBatchSave(
+    _probeBatch: IPLProbeBatch,
+    _serializedObject: IPLSerializedObject,
+) {
+}
+
+pub unsafe extern "C" fn iplProbeBatchGetNumProbes(_probeBatch: IPLProbeBatch) -> IPLint32 {
+    0
+}
+
+pub unsafe extern "C" fn iplProbeBatchAddProbeArray(
+    _probeBatch: IPLProbeBatch,
+    _probeArray: IPLProbeArray,
+) {
+}
+
+pub unsafe extern "C" fn iplProbeBatchCommit(_probeBatch: IPLProbeBatch) {}
+
+pub unsafe extern "C" fn iplSimulatorCreate(
+    _context: IPLContext,
+    _settings: *mut IPLSimulationSettings,
+    _simulator: *mut IPLSimulator,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplSimulatorRetain(simulator: IPLSimulator) -> IPLSimulator {
+    simulator
+}
+
+pub unsafe extern "C" fn iplSimulatorRelease(_simulator: *mut IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSimulatorSetScene(_simulator: IPLSimulator, _scene: IPLScene) {}
+
+pub unsafe extern "C" fn iplSimulatorAddProbeBatch(
+    _simulator: IPLSimulator,
+    _probeBatch: IPLProbeBatch,
+) {
+}
+
+pub unsafe extern "C" fn iplSimulatorRemoveProbeBatch(
+    _simulator: IPLSimulator,
+    _probeBatch: IPLProbeBatch,
+) {
+}
+
+pub unsafe extern "C" fn iplSimulatorSetSharedInputs(
+    _simulator: IPLSimulator,
+    _flags: IPLSimulationFlags,
+    _sharedInputs: *mut IPLSimulationSharedInputs,
+) {
+}
+
+pub unsafe extern "C" fn iplSimulatorCommit(_simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSimulatorRunDirect(_simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSimulatorRunReflections(_simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSimulatorRunPathing(_simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSourceCreate(
+    _simulator: IPLSimulator,
+    _settings: *mut IPLSourceSettings,
+    _source: *mut IPLSource,
+) -> IPLerror {
+    IPLerror_IPL_STATUS_SUCCESS
+}
+
+pub unsafe extern "C" fn iplSourceRetain(source: IPLSource) -> IPLSource {
+    source
+}
+
+pub unsafe extern "C" fn iplSourceRelease(_source: *mut IPLSource) {}
+
+pub unsafe extern "C" fn iplSourceAdd(_source: IPLSource, _simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSourceRemove(_source: IPLSource, _simulator: IPLSimulator) {}
+
+pub unsafe extern "C" fn iplSourceSetInputs(
+    _source: IPLSource,
+    _flags: IPLSimulationFlags,
+    _inputs: *mut IPLSimulationInputs,
+) {
+}
+
+pub unsafe extern "C" fn iplSourceGetOutputs(
+    _source: IPLSource,
+    _flags: IPLSimulationFlags,
+    _outputs: *mut IPLSimulationOutputs,
+) {
+}
@@ -1,9 +1,19 @@
-use std::{env, path::PathBuf};
+use std::{env, fs, path::PathBuf};
 
 fn main() {
-    let in_dir = PathBuf::from(env::var("STEAMAUDIO_DIR").expect("STEAMAUDIO_DIR is not defined"));
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
 
+    // With the mock feature neither the headers nor the shared library of Steam
+    // Audio are required, the checked-in bindings stub out all functions, which
+    // allows testing code that doesn't depend on Steam Audio without the SDK.
+    if env::var_os("CARGO_FEATURE_MOCK").is_some() {
+        println!("cargo:rerun-if-changed=bindings.rs");
+        fs::copy("bindings.rs", out_dir.join("bindings.rs")).expect("Failed to copy bindings");
+        return;
+    }
+
+    let in_dir = PathBuf::from(env::var("STEAMAUDIO_DIR").expect("STEAMAUDIO_DIR is not defined"));
+
     bindgen::Builder::default()
         .header(in_dir.join("include/phonon.h").to_str().unwrap())
         .clang_arg("-Duint8_t=char")
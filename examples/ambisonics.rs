@@ -19,12 +19,12 @@ use steamaudio::{
     effect::{AmbisonicsDecodeEffectParams, AmbisonicsEncodeEffectParams, Effect},
     geometry::Orientation,
     simulation::{AirAbsorptionModel, Directivity, DistanceAttenuationModel},
-    transform::transform,
+    transform::{default_output_sampling_rate, transform},
 };
 
 fn main() {
     let ambisonics_order = 2;
-    let sampling_rate = 44100;
+    let sampling_rate = default_output_sampling_rate().unwrap_or(44100);
     let frame_size = 1024;
     let speaker_layout = SpeakerLayout::Stereo;
     let binaural = true;
@@ -41,11 +41,10 @@ fn main() {
     ));
 
     // Simulator is used to render sources
-    let simulator = context.create_simulator(sampling_rate, frame_size).unwrap();
+    let mut simulator = context.create_simulator(sampling_rate, frame_size).unwrap();
 
     // Create source and set it to active, and commit to the simulator
-    let mut simulator_source = simulator.create_source().unwrap();
-    simulator_source.set_active(true);
+    let mut simulator_source = simulator.create_source_active(true).unwrap();
     simulator_source.set_distance_attenuation(DistanceAttenuationModel::Default);
     simulator_source.set_air_absorption(AirAbsorptionModel::Exponential([0.0, 1.0, 4.0]));
     simulator_source.set_directivity(Directivity::Dipole {
@@ -58,10 +57,7 @@ fn main() {
     {
         // Source to play
         let source = UniformSourceIterator::new(
-            Decoder::new(
-                File::open(r"example.mp3").unwrap(),
-            )
-            .unwrap(),
+            Decoder::new(File::open(r"example.mp3").unwrap()).unwrap(),
             1,
             sampling_rate,
         );
@@ -86,10 +82,7 @@ fn main() {
             move |in_, out| {
                 direct_effect.apply(&simulator_source, in_, &mut direct_buffer);
                 ambisonics_encode_effect.apply(
-                    AmbisonicsEncodeEffectParams {
-                        direction: *direction.lock().unwrap(),
-                        order: ambisonics_order,
-                    },
+                    AmbisonicsEncodeEffectParams::new(*direction.lock().unwrap(), ambisonics_order),
                     &direct_buffer,
                     out,
                 );
@@ -99,7 +92,14 @@ fn main() {
         ));
     }
 
-    let (_stream, stream_handle) = rodio::OutputStream::try_default().unwrap();
+    // Opening the output stream fails on machines without an audio device
+    let (_stream, stream_handle) = match rodio::OutputStream::try_default() {
+        Ok(stream) => stream,
+        Err(error) => {
+            eprintln!("Failed to open the audio output: {error}");
+            return;
+        }
+    };
 
     // Decode the sound field from the mixer and play the result
     let ambisonics_decode_effect = context
@@ -133,20 +133,24 @@ fn main() {
         ))
         .unwrap();
 
-    // Rotate the source around the listener
+    // Rotate the source around the listener, while the listener slowly turns
+    // its head
     let mut i = 0f32;
     loop {
-        {
-            let mut direction = direction.lock().unwrap();
-            direction.x = i.sin();
-            direction.z = i.cos();
-
-            simulator_source.set_source(Orientation {
-                translation: *direction,
-                rotation: Default::default(),
-            });
-            simulator.run_direct();
-        }
+        let listener = Orientation {
+            translation: Vec3::ZERO,
+            rotation: Quat::from_rotation_y(i * 0.25),
+        };
+        simulator.set_listener(listener);
+        simulator_source.set_source(Orientation {
+            translation: Vec3::new(i.sin(), 0.0, i.cos()),
+            rotation: Default::default(),
+        });
+        simulator.run_direct();
+
+        // The encode direction has to be relative to the listener's orientation,
+        // not only its position
+        *direction.lock().unwrap() = simulator_source.relative_direction(&context, listener);
 
         i += 0.01;
         sleep(Duration::from_millis(20))
@@ -0,0 +1,52 @@
+/// Demonstrates recording an Ambisonics bus to an AmbiX (ACN/SN3D) B-format WAV
+/// file, without playing it back.
+use std::{f32::consts::TAU, fs::File, io::BufWriter};
+
+use glam::Vec3;
+
+use steamaudio::{ambisonics_channels, buffer::Buffer, context::Context, wav::WavWriter};
+
+fn main() {
+    let ambisonics_order = 2;
+    let sampling_rate = 48000;
+    let frame_size = 1024;
+    let duration = 5.0;
+
+    // Create context
+    let context = Context::new().unwrap();
+
+    // Create ambisonics bus which encodes the sources to the sound field
+    let mut ambisonics_bus = context
+        .create_ambisonics_bus(sampling_rate, frame_size, ambisonics_order)
+        .unwrap();
+
+    let mut source_buffer = Buffer::new(1, frame_size);
+    let mut ambisonics_buffer = Buffer::new(ambisonics_channels(ambisonics_order), frame_size);
+    let mut wav_writer = WavWriter::new(
+        BufWriter::new(File::create("recording.wav").unwrap()),
+        ambisonics_channels(ambisonics_order),
+        sampling_rate,
+    )
+    .unwrap();
+
+    let frames = (duration * sampling_rate as f32 / frame_size as f32).ceil() as u32;
+    for frame in 0..frames {
+        let time = (frame * frame_size) as f32 / sampling_rate as f32;
+
+        // Generate a 440 Hz tone, which circles around the listener once per
+        // second
+        for (i, sample) in source_buffer.data()[0].iter_mut().enumerate() {
+            *sample = (TAU * 440.0 * (time + i as f32 / sampling_rate as f32)).sin() * 0.5;
+        }
+        let direction = Vec3::new((TAU * time).sin(), 0.0, -(TAU * time).cos());
+
+        ambisonics_bus.add_source(direction, &source_buffer);
+        ambisonics_bus.drain(&mut ambisonics_buffer);
+
+        // Steam Audio uses N3D normalization, while AmbiX uses SN3D
+        ambisonics_buffer.n3d_to_sn3d();
+        wav_writer.write(&ambisonics_buffer).unwrap();
+    }
+
+    wav_writer.finish().unwrap();
+}
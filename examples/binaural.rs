@@ -18,11 +18,11 @@ use steamaudio::{
     effect::{BinauralEffectParams, Effect, HrtfInterpolation},
     geometry::Orientation,
     simulation::{AirAbsorptionModel, DistanceAttenuationModel},
-    transform::transform,
+    transform::{default_output_sampling_rate, transform},
 };
 
 fn main() {
-    let sampling_rate = 44100;
+    let sampling_rate = default_output_sampling_rate().unwrap_or(44100);
     let frame_size = 1024;
     let speaker_layout = SpeakerLayout::Stereo;
 
@@ -38,8 +38,7 @@ fn main() {
     let simulator = context.create_simulator(sampling_rate, frame_size).unwrap();
 
     // Create source and set it to active, and commit to the simulator
-    let mut simulator_source = simulator.create_source().unwrap();
-    simulator_source.set_active(true);
+    let mut simulator_source = simulator.create_source_active(true).unwrap();
     simulator_source.set_distance_attenuation(DistanceAttenuationModel::Default);
     simulator_source.set_air_absorption(AirAbsorptionModel::Exponential([0.0, 1.0, 4.0]));
     simulator.commit();
@@ -89,7 +88,14 @@ fn main() {
         ));
     }
 
-    let (_stream, stream_handle) = rodio::OutputStream::try_default().unwrap();
+    // Opening the output stream fails on machines without an audio device
+    let (_stream, stream_handle) = match rodio::OutputStream::try_default() {
+        Ok(stream) => stream,
+        Err(error) => {
+            eprintln!("Failed to open the audio output: {error}");
+            return;
+        }
+    };
 
     stream_handle.play_raw(stereo_mixer).unwrap();
 
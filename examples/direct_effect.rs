@@ -1,7 +1,4 @@
 /// Demonstrates the direct_effect occlusion and transmission.
-/// Note that in IPLDirectEffectParams the transmissionType needs to be set to
-/// IPLTransmissionType_IPL_TRANSMISSIONTYPE_FREQDEPENDENT in order to properly
-/// hear the transmission effect.
 use std::{fs::File, thread::sleep, time::Duration};
 
 use glam::Vec3;
@@ -12,12 +9,16 @@ use rodio::{
 };
 
 use steamaudio::{
-    buffer::SpeakerLayout, context::Context, effect::Effect, geometry::Orientation,
-    transform::transform,
+    buffer::SpeakerLayout,
+    context::Context,
+    effect::Effect,
+    geometry::Orientation,
+    simulation::TransmissionType,
+    transform::{default_output_sampling_rate, transform},
 };
 
 fn main() {
-    let sampling_rate = 44100;
+    let sampling_rate = default_output_sampling_rate().unwrap_or(44100);
     let frame_size = 1024;
     let speaker_layout = SpeakerLayout::Stereo;
 
@@ -65,10 +66,9 @@ fn main() {
     simulator.set_scene(&scene);
 
     // Create source and set it to active
-    let mut simulator_source = simulator.create_source().unwrap();
+    let mut simulator_source = simulator.create_source_active(true).unwrap();
     simulator_source.set_occlusion();
-    simulator_source.set_transmission(1);
-    simulator_source.set_active(true);
+    simulator_source.set_transmission(1, TransmissionType::FrequencyDependent);
 
     // Put the source behind the mesh
     simulator_source.set_source(Orientation {
@@ -112,7 +112,14 @@ fn main() {
         frame_size,
     ));
 
-    let (_stream, stream_handle) = rodio::OutputStream::try_default().unwrap();
+    // Opening the output stream fails on machines without an audio device
+    let (_stream, stream_handle) = match rodio::OutputStream::try_default() {
+        Ok(stream) => stream,
+        Err(error) => {
+            eprintln!("Failed to open the audio output: {error}");
+            return;
+        }
+    };
 
     stream_handle.play_raw(stereo_mixer).unwrap();
 
@@ -0,0 +1,232 @@
+/// Demonstrates direct sound together with simulated reflections, both rendered
+/// to an ambisonics bus which is decoded binaurally.
+use std::{
+    fs::File,
+    sync::{Arc, Mutex},
+    thread::{sleep, spawn},
+    time::Duration,
+};
+
+use glam::{Quat, Vec3};
+use rodio::{
+    dynamic_mixer,
+    source::{UniformSourceIterator, Zero},
+    Decoder,
+};
+
+use steamaudio::{
+    ambisonics_channels,
+    buffer::{Buffer, SpeakerLayout},
+    context::Context,
+    effect::{AmbisonicsDecodeEffectParams, AmbisonicsEncodeEffectParams, Effect},
+    geometry::Orientation,
+    scene::Material,
+    simulation::{DistanceAttenuationModel, SimulationSettings},
+    transform::{default_output_sampling_rate, tee, transform},
+};
+
+fn main() {
+    let ambisonics_order = 1;
+    let sampling_rate = default_output_sampling_rate().unwrap_or(44100);
+    let frame_size = 1024;
+    let speaker_layout = SpeakerLayout::Stereo;
+    let reflections_duration = 1.0;
+
+    // Create context
+    let context = Context::new().unwrap();
+
+    // Create a scene containing a 10 x 3 x 10 meter room
+    let scene = context.create_scene().unwrap();
+    let vertices: [[f32; 3]; 8] = [
+        [-5.0, 0.0, -5.0],
+        [5.0, 0.0, -5.0],
+        [5.0, 0.0, 5.0],
+        [-5.0, 0.0, 5.0],
+        [-5.0, 3.0, -5.0],
+        [5.0, 3.0, -5.0],
+        [5.0, 3.0, 5.0],
+        [-5.0, 3.0, 5.0],
+    ];
+    let triangles: [[u32; 3]; 12] = [
+        // Floor
+        [0, 2, 1],
+        [0, 3, 2],
+        // Ceiling
+        [4, 5, 6],
+        [4, 6, 7],
+        // Walls
+        [0, 1, 5],
+        [0, 5, 4],
+        [1, 2, 6],
+        [1, 6, 5],
+        [2, 3, 7],
+        [2, 7, 6],
+        [3, 0, 4],
+        [3, 4, 7],
+    ];
+    let materials = [Material::CONCRETE, Material::WOOD, Material::PLASTER];
+    let material_indices: [u32; 12] = [1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0];
+    // The room stays in the scene for as long as the handle is alive
+    let _room = scene
+        .add_static_mesh(
+            triangles.as_slice(),
+            vertices.as_slice(),
+            material_indices.as_slice(),
+            materials.as_slice(),
+        )
+        .unwrap();
+
+    // Simulator is used to render sources, and simulates reflections in the
+    // scene
+    let mut simulator = context
+        .create_reflections_simulator(
+            SimulationSettings {
+                maximum_duration: reflections_duration,
+                maximum_order: ambisonics_order,
+                maximum_sources: 8,
+                ..SimulationSettings::with_reflections(sampling_rate, frame_size)
+            },
+            &scene,
+        )
+        .unwrap();
+    let listener = Orientation {
+        translation: Vec3::new(0.0, 1.5, 0.0),
+        rotation: Default::default(),
+    };
+    simulator.set_listener(listener);
+
+    // Create source and set it to active, and commit to the simulator
+    let mut simulator_source = simulator.create_source_active(true).unwrap();
+    simulator_source.set_distance_attenuation(DistanceAttenuationModel::Default);
+    simulator_source.set_reflections();
+    simulator.commit();
+
+    // Run reflections on a worker thread, as they are too expensive to run on
+    // the audio or main thread
+    {
+        let simulator = simulator.clone();
+        spawn(move || loop {
+            simulator.run_reflections();
+            sleep(Duration::from_millis(100));
+        });
+    }
+
+    // Create ambisonics mixer for the final mix
+    let (ambisonics_mixer_controller, ambisonics_mixer) =
+        dynamic_mixer::mixer(ambisonics_channels(ambisonics_order), sampling_rate);
+    ambisonics_mixer_controller.add(Zero::new(
+        ambisonics_channels(ambisonics_order),
+        sampling_rate,
+    ));
+
+    let direction = Arc::new(Mutex::new(Vec3::Z));
+    {
+        // Source to play
+        let source = UniformSourceIterator::new(
+            Decoder::new(File::open(r"example.mp3").unwrap()).unwrap(),
+            1,
+            sampling_rate,
+        );
+
+        // Create direct effect which applies the attenuation, and ambisonics
+        // encode effect which encodes the sound to the sound field
+        let direct_effect = context
+            .create_direct_effect(sampling_rate, frame_size, 1)
+            .unwrap();
+        let ambisonics_encode_effect = context
+            .create_ambisonics_encode_effect(sampling_rate, frame_size, ambisonics_order)
+            .unwrap();
+        let direct_source = simulator_source.clone();
+        let mut direct_buffer = Buffer::new(1, frame_size);
+        let direction = direction.clone();
+
+        // Create reflection effect which renders the simulated reflections
+        // directly to the sound field
+        let reflection_effect = context
+            .create_reflection_effect_for(
+                &simulator,
+                ambisonics_channels(ambisonics_order),
+                reflections_duration,
+            )
+            .unwrap();
+        let reflection_source = simulator_source.clone();
+
+        // Transform the source, summing the direct and reflected sound
+        ambisonics_mixer_controller.add(transform(
+            source,
+            tee(
+                move |in_, out| {
+                    direct_effect.apply(&direct_source, in_, &mut direct_buffer);
+                    ambisonics_encode_effect.apply(
+                        AmbisonicsEncodeEffectParams::new(
+                            *direction.lock().unwrap(),
+                            ambisonics_order,
+                        ),
+                        &direct_buffer,
+                        out,
+                    );
+                },
+                move |in_, out| reflection_effect.apply(&reflection_source, in_, out),
+                ambisonics_channels(ambisonics_order),
+                frame_size,
+            ),
+            ambisonics_channels(ambisonics_order),
+            frame_size,
+        ));
+    }
+
+    // Opening the output stream fails on machines without an audio device
+    let (_stream, stream_handle) = match rodio::OutputStream::try_default() {
+        Ok(stream) => stream,
+        Err(error) => {
+            eprintln!("Failed to open the audio output: {error}");
+            return;
+        }
+    };
+
+    // Decode the sound field from the mixer and play the result
+    let ambisonics_decode_effect = context
+        .create_ambisonics_decode_effect(
+            sampling_rate,
+            frame_size,
+            speaker_layout.clone(),
+            &context.create_hrtf(sampling_rate, frame_size).unwrap(),
+            ambisonics_order,
+        )
+        .unwrap();
+    stream_handle
+        .play_raw(transform(
+            ambisonics_mixer,
+            move |in_, out| {
+                ambisonics_decode_effect.apply(
+                    AmbisonicsDecodeEffectParams {
+                        orientation: Orientation {
+                            translation: Default::default(),
+                            rotation: Quat::default(),
+                        },
+                        order: ambisonics_order,
+                        binaural: true,
+                    },
+                    in_,
+                    out,
+                )
+            },
+            speaker_layout.channels(),
+            frame_size,
+        ))
+        .unwrap();
+
+    // Move the source around the listener
+    let mut i = 0f32;
+    loop {
+        simulator_source.set_source(Orientation {
+            translation: listener.translation + Vec3::new(i.sin(), 0.0, i.cos()) * 3.0,
+            rotation: Default::default(),
+        });
+        simulator.run_direct();
+        *direction.lock().unwrap() = simulator_source.relative_direction(&context, listener);
+
+        i += 0.01;
+        sleep(Duration::from_millis(20))
+    }
+}
@@ -0,0 +1,181 @@
+/// Demonstrates rendering only the simulated reverb of the listener's
+/// surroundings at a controllable level, as a send with the dry signal routed
+/// elsewhere.
+use std::{
+    fs::File,
+    sync::{Arc, Mutex},
+    thread::{sleep, spawn},
+    time::Duration,
+};
+
+use glam::{Quat, Vec3};
+use rodio::{source::UniformSourceIterator, Decoder};
+
+use steamaudio::{
+    ambisonics_channels,
+    buffer::{Buffer, SpeakerLayout},
+    context::Context,
+    effect::{AmbisonicsDecodeEffectParams, Effect},
+    geometry::Orientation,
+    scene::Material,
+    simulation::SimulationSettings,
+    transform::{default_output_sampling_rate, transform},
+};
+
+fn main() {
+    let ambisonics_order = 1;
+    let sampling_rate = default_output_sampling_rate().unwrap_or(44100);
+    let frame_size = 1024;
+    let speaker_layout = SpeakerLayout::Stereo;
+    let reverb_duration = 2.0;
+
+    // Create context
+    let context = Context::new().unwrap();
+
+    // Create a scene containing a 20 x 6 x 20 meter hall
+    let scene = context.create_scene().unwrap();
+    let vertices: [[f32; 3]; 8] = [
+        [-10.0, 0.0, -10.0],
+        [10.0, 0.0, -10.0],
+        [10.0, 0.0, 10.0],
+        [-10.0, 0.0, 10.0],
+        [-10.0, 6.0, -10.0],
+        [10.0, 6.0, -10.0],
+        [10.0, 6.0, 10.0],
+        [-10.0, 6.0, 10.0],
+    ];
+    let triangles: [[u32; 3]; 12] = [
+        // Floor
+        [0, 2, 1],
+        [0, 3, 2],
+        // Ceiling
+        [4, 5, 6],
+        [4, 6, 7],
+        // Walls
+        [0, 1, 5],
+        [0, 5, 4],
+        [1, 2, 6],
+        [1, 6, 5],
+        [2, 3, 7],
+        [2, 7, 6],
+        [3, 0, 4],
+        [3, 4, 7],
+    ];
+    let materials = [Material::CONCRETE];
+    let material_indices = [0u32; 12];
+    let _hall = scene
+        .add_static_mesh(
+            triangles.as_slice(),
+            vertices.as_slice(),
+            material_indices.as_slice(),
+            materials.as_slice(),
+        )
+        .unwrap();
+
+    // Create a simulator which simulates reflections in the scene
+    let mut simulator = context
+        .create_reflections_simulator(
+            SimulationSettings {
+                maximum_duration: reverb_duration,
+                maximum_order: ambisonics_order,
+                maximum_sources: 1,
+                ..SimulationSettings::with_reflections(sampling_rate, frame_size)
+            },
+            &scene,
+        )
+        .unwrap();
+    simulator.set_listener(Orientation {
+        translation: Vec3::new(0.0, 1.5, 0.0),
+        rotation: Default::default(),
+    });
+
+    // The reverb source is positioned at the listener, and captures the reverb
+    // of the listener's surroundings
+    let reverb_source = simulator.create_reverb_source().unwrap();
+    simulator.commit();
+
+    // Run reflections on a worker thread, as they are too expensive to run on
+    // the audio or main thread
+    {
+        let simulator = simulator.clone();
+        spawn(move || loop {
+            simulator.run_reflections();
+            sleep(Duration::from_millis(100));
+        });
+    }
+
+    // Source to play
+    let source = UniformSourceIterator::new(
+        Decoder::new(File::open(r"example.mp3").unwrap()).unwrap(),
+        1,
+        sampling_rate,
+    );
+
+    // Create reflection effect which renders only the reverb to the sound
+    // field, and ambisonics decode effect which decodes it binaurally
+    let reflection_effect = context
+        .create_reflection_effect_for(
+            &simulator,
+            ambisonics_channels(ambisonics_order),
+            reverb_duration,
+        )
+        .unwrap();
+    let ambisonics_decode_effect = context
+        .create_ambisonics_decode_effect(
+            sampling_rate,
+            frame_size,
+            speaker_layout.clone(),
+            &context.default_hrtf(sampling_rate, frame_size).unwrap(),
+            ambisonics_order,
+        )
+        .unwrap();
+    let mut reverb_buffer = Buffer::new(ambisonics_channels(ambisonics_order), frame_size);
+    let wet = Arc::new(Mutex::new(0.5f32));
+
+    // Transform the source into the scaled reverb only, the dry signal would be
+    // routed elsewhere, e.g. through a direct effect on another bus
+    let source = {
+        let wet = wet.clone();
+        transform(
+            source,
+            move |in_, out| {
+                reflection_effect.apply(&reverb_source, in_, &mut reverb_buffer);
+                ambisonics_decode_effect.apply(
+                    AmbisonicsDecodeEffectParams {
+                        orientation: Orientation {
+                            translation: Default::default(),
+                            rotation: Quat::default(),
+                        },
+                        order: ambisonics_order,
+                        binaural: true,
+                    },
+                    &reverb_buffer,
+                    out,
+                );
+                out.scale(*wet.lock().unwrap());
+            },
+            speaker_layout.channels(),
+            frame_size,
+        )
+    };
+
+    // Opening the output stream fails on machines without an audio device
+    let (_stream, stream_handle) = match rodio::OutputStream::try_default() {
+        Ok(stream) => stream,
+        Err(error) => {
+            eprintln!("Failed to open the audio output: {error}");
+            return;
+        }
+    };
+
+    stream_handle.play_raw(source).unwrap();
+
+    // Slowly sweep the reverb level between silent and full level
+    let mut i = 0f32;
+    loop {
+        *wet.lock().unwrap() = (i.sin() + 1.0) * 0.5;
+
+        i += 0.01;
+        sleep(Duration::from_millis(20))
+    }
+}
@@ -1,7 +1,19 @@
+use std::sync::Mutex;
+
 use glam::Vec3;
 
-use crate::ffi;
+use crate::{
+    ambisonics_channels,
+    error::{Error, Result},
+    ffi,
+};
 
+/// Deinterleaved audio samples, with one vector of samples per channel.
+///
+/// The samples are owned by the buffer, while Steam Audio accesses them
+/// through pointers to each channel, `inner.numChannels` and
+/// `inner.numSamples` therefore always match the number and length of the
+/// channel vectors, which must not be resized or replaced.
 pub struct Buffer {
     pub(crate) inner: ffi::IPLAudioBuffer,
 
@@ -14,6 +26,10 @@ impl Buffer {
         vec![vec![0.0; samples as usize]; channels as usize].into()
     }
 
+    /// Returns the samples of all channels.
+    ///
+    /// The channels can be modified in place, but must not be resized or
+    /// replaced, as Steam Audio still refers to the original allocations.
     pub fn data(&mut self) -> &mut Vec<Vec<f32>> {
         &mut self.data
     }
@@ -22,9 +38,173 @@ impl Buffer {
         self.inner.numChannels as u16
     }
 
+    /// Returns the samples of the given channel.
+    pub fn channel(&self, index: usize) -> &[f32] {
+        &self.data[index]
+    }
+
+    /// Returns the samples of the given channel, or `None` if the buffer has
+    /// no such channel.
+    pub fn channel_mut(&mut self, index: usize) -> Option<&mut [f32]> {
+        self.data.get_mut(index).map(Vec::as_mut_slice)
+    }
+
+    /// Returns an iterator over the samples of all channels.
+    pub fn channels_iter(&self) -> impl Iterator<Item = &[f32]> {
+        self.data.iter().map(Vec::as_slice)
+    }
+
     pub fn samples(&self) -> u32 {
         self.inner.numSamples as u32
     }
+
+    /// Creates a buffer from interleaved 16-bit PCM samples, which are
+    /// normalized to [-1, 1].
+    pub fn from_i16_interleaved(channels: u16, samples: &[i16]) -> Self {
+        assert!(channels > 0 && samples.len().is_multiple_of(channels as usize));
+
+        let mut data = (0..channels)
+            .map(|_| Vec::with_capacity(samples.len() / channels as usize))
+            .collect::<Vec<_>>();
+        for frame in samples.chunks_exact(channels as usize) {
+            for (channel, sample) in data.iter_mut().zip(frame) {
+                channel.push(*sample as f32 / -(i16::MIN as f32));
+            }
+        }
+        data.into()
+    }
+
+    /// Returns the samples as interleaved 16-bit PCM, samples outside of
+    /// [-1, 1] are clipped.
+    pub fn to_i16_interleaved(&self) -> Vec<i16> {
+        let mut samples = Vec::with_capacity(self.channels() as usize * self.samples() as usize);
+        for frame in 0..self.samples() as usize {
+            for channel in &self.data {
+                samples.push(
+                    (channel[frame] * -(i16::MIN as f32)).clamp(i16::MIN as f32, i16::MAX as f32)
+                        as i16,
+                );
+            }
+        }
+        samples
+    }
+
+    /// Splits the buffer into buffers of `frame_size` samples, e.g. to run
+    /// effects over buffers of a host with a different block size. The last
+    /// buffer is padded with silence.
+    ///
+    /// Each chunk is copied into a newly allocated buffer, as the last one
+    /// can't borrow its padding, which makes this unsuitable for the audio
+    /// thread.
+    pub fn chunks(&self, frame_size: u32) -> impl Iterator<Item = Buffer> + '_ {
+        assert!(frame_size > 0);
+
+        (0..self.samples())
+            .step_by(frame_size as usize)
+            .map(move |start| {
+                Buffer::from(
+                    self.data
+                        .iter()
+                        .map(|channel| {
+                            let mut chunk = channel[start as usize..]
+                                .iter()
+                                .take(frame_size as usize)
+                                .copied()
+                                .collect::<Vec<_>>();
+                            chunk.resize(frame_size as usize, 0.0);
+                            chunk
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+    }
+
+    /// Concatenates buffers with the same number of channels, e.g. the outputs
+    /// of effects applied to [`Buffer::chunks`].
+    pub fn join<'a>(buffers: impl IntoIterator<Item = &'a Buffer>) -> Buffer {
+        let mut data = Vec::<Vec<f32>>::new();
+        for buffer in buffers {
+            if data.is_empty() {
+                data.resize(buffer.data.len(), Vec::new());
+            }
+            assert_eq!(data.len(), buffer.data.len());
+
+            for (channel, buffer_channel) in data.iter_mut().zip(&buffer.data) {
+                channel.extend_from_slice(buffer_channel);
+            }
+        }
+        assert!(!data.is_empty(), "at least one buffer must be joined");
+
+        data.into()
+    }
+
+    /// Scales all channels uniformly by the given gain.
+    pub fn scale(&mut self, gain: f32) {
+        for channel in &mut self.data {
+            for sample in channel {
+                *sample *= gain;
+            }
+        }
+    }
+
+    /// Returns a copy of an Ambisonics buffer reduced to a lower order, e.g. to
+    /// save decoding cost for distant sources.
+    ///
+    /// Ambisonics channels are ordered by ACN and normalized per channel, so
+    /// the lower order sound field consists of the first `(order + 1)^2`
+    /// channels without any renormalization.
+    pub fn reduce_ambisonics_order(&self, order: u8) -> Buffer {
+        let channels = ambisonics_channels(order) as usize;
+        assert!(channels <= self.data.len());
+
+        self.data[..channels].to_vec().into()
+    }
+
+    /// Limits all samples to [-1, 1] using a smooth saturation curve, so that
+    /// loud mixes don't hard clip at the output device. Quiet signals are
+    /// left almost unchanged.
+    pub fn soft_clip(&mut self) {
+        for channel in &mut self.data {
+            for sample in channel {
+                *sample = sample.tanh();
+            }
+        }
+    }
+
+    /// Converts an Ambisonics buffer from the N3D normalization used by Steam
+    /// Audio to SN3D, as used by the AmbiX format, e.g. before writing it to a
+    /// file for use in other tools.
+    pub fn n3d_to_sn3d(&mut self) {
+        for (acn, channel) in self.data.iter_mut().enumerate() {
+            let degree = (acn as f32).sqrt().floor();
+            let gain = 1.0 / (2.0 * degree + 1.0).sqrt();
+            for sample in channel {
+                *sample *= gain;
+            }
+        }
+    }
+
+    /// Scales an Ambisonics buffer uniformly, so that the RMS over all samples
+    /// of all channels matches `target_rms`, e.g. before decoding a sound field
+    /// summed from many sources. Silent buffers are left unchanged.
+    pub fn normalize_ambisonic(&mut self, target_rms: f32) {
+        let samples = self.data.iter().map(Vec::len).sum::<usize>();
+        if samples == 0 {
+            return;
+        }
+
+        let rms = (self
+            .data
+            .iter()
+            .flatten()
+            .map(|sample| sample * sample)
+            .sum::<f32>()
+            / samples as f32)
+            .sqrt();
+        if rms > 0.0 {
+            self.scale(target_rms / rms);
+        }
+    }
 }
 
 impl From<Vec<Vec<f32>>> for Buffer {
@@ -46,8 +226,63 @@ impl From<Vec<Vec<f32>>> for Buffer {
     }
 }
 
+impl std::fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Buffer")
+            .field("channels", &self.channels())
+            .field("samples", &self.samples())
+            .finish()
+    }
+}
+
 unsafe impl Send for Buffer {}
 
+/// A pool of equally sized buffers, so that per-source buffers can be
+/// allocated up front, e.g. based on the maximum number of sources, and
+/// reused instead of allocating when sources are created on the audio thread.
+pub struct BufferPool {
+    channels: u16,
+    samples: u32,
+    buffers: Mutex<Vec<Buffer>>,
+}
+
+impl BufferPool {
+    /// Creates a pool with `capacity` preallocated buffers.
+    pub fn new(channels: u16, samples: u32, capacity: usize) -> Self {
+        Self {
+            channels,
+            samples,
+            buffers: Mutex::new(
+                (0..capacity)
+                    .map(|_| Buffer::new(channels, samples))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Takes a buffer from the pool, allocating a new one if the pool is
+    /// exhausted. The contents of a reused buffer are not cleared.
+    pub fn acquire(&self) -> Buffer {
+        self.buffers
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| Buffer::new(self.channels, self.samples))
+    }
+
+    /// Returns a buffer to the pool, buffers of a different size are dropped.
+    pub fn release(&self, buffer: Buffer) {
+        if buffer.channels() == self.channels && buffer.samples() == self.samples {
+            self.buffers.lock().unwrap().push(buffer);
+        }
+    }
+
+    /// Returns the number of buffers available without allocating.
+    pub fn available(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+}
+
 /// Describes a standard or custom speaker layout.
 #[derive(Clone)]
 pub enum SpeakerLayout {
@@ -67,11 +302,36 @@ pub enum SpeakerLayout {
     /// left, side right.
     Surround7_1,
 
-    /// User-defined speaker layout.
-    Custom(Vec<Vec3>),
+    /// User-defined speaker layout, created with [`SpeakerLayout::custom`].
+    /// Channel `i` is played back from the speaker at position `i`.
+    Custom(CustomSpeakers),
 }
 
+/// The speaker positions of a user-defined speaker layout, which has at least
+/// one speaker, see [`SpeakerLayout::custom`].
+#[derive(Clone)]
+pub struct CustomSpeakers(Vec<Vec3>);
+
 impl SpeakerLayout {
+    /// Creates a user-defined speaker layout, returns
+    /// [`Error::EmptySpeakerLayout`] if no speakers are given.
+    pub fn custom(speakers: Vec<Vec3>) -> Result<Self> {
+        if speakers.is_empty() {
+            return Err(Error::EmptySpeakerLayout);
+        }
+
+        Ok(SpeakerLayout::Custom(CustomSpeakers(speakers)))
+    }
+
+    /// The positions of the speakers of a user-defined speaker layout, in
+    /// channel order.
+    pub fn speakers(&self) -> Option<&[Vec3]> {
+        match self {
+            SpeakerLayout::Custom(CustomSpeakers(value)) => Some(value),
+            _ => None,
+        }
+    }
+
     pub fn channels(&self) -> u16 {
         match self {
             SpeakerLayout::Mono => 1,
@@ -79,13 +339,15 @@ impl SpeakerLayout {
             SpeakerLayout::Quadraphonic => 4,
             SpeakerLayout::Surround5_1 => 6,
             SpeakerLayout::Surround7_1 => 8,
-            SpeakerLayout::Custom(value) => value.len() as u16,
+            SpeakerLayout::Custom(CustomSpeakers(value)) => value.len() as u16,
         }
     }
 }
 
-impl From<SpeakerLayout> for ffi::IPLSpeakerLayout {
-    fn from(value: SpeakerLayout) -> ffi::IPLSpeakerLayout {
+/// The returned layout borrows the speaker positions of a user-defined speaker
+/// layout, so the speaker layout must outlive it.
+impl From<&SpeakerLayout> for ffi::IPLSpeakerLayout {
+    fn from(value: &SpeakerLayout) -> ffi::IPLSpeakerLayout {
         match value {
             SpeakerLayout::Mono => ffi::IPLSpeakerLayout {
                 type_: ffi::IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_MONO,
@@ -112,15 +374,14 @@ impl From<SpeakerLayout> for ffi::IPLSpeakerLayout {
                 numSpeakers: 0,
                 speakers: std::ptr::null_mut(),
             },
-            SpeakerLayout::Custom(speakers) => ffi::IPLSpeakerLayout {
-                type_: ffi::IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_CUSTOM,
-                numSpeakers: speakers.len() as i32,
-                speakers: speakers
-                    .iter()
-                    .map(|speaker| speaker.into())
-                    .collect::<Vec<_>>()
-                    .as_mut_ptr(),
-            },
+            SpeakerLayout::Custom(CustomSpeakers(speakers)) => {
+                // `Vec3` has the same layout as `IPLVector3`.
+                ffi::IPLSpeakerLayout {
+                    type_: ffi::IPLSpeakerLayoutType_IPL_SPEAKERLAYOUTTYPE_CUSTOM,
+                    numSpeakers: speakers.len() as i32,
+                    speakers: speakers.as_ptr() as *mut ffi::IPLVector3,
+                }
+            }
         }
     }
 }
@@ -1,8 +1,14 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
 use tracing::{debug, error, info, warn};
 
 use crate::{
     error::{check, Result},
     ffi,
+    hrtf::Hrtf,
 };
 
 /// A context object, which controls low-level operations of Steam Audio.
@@ -10,12 +16,32 @@ use crate::{
 /// program, before calling any other API functions
 pub struct Context {
     pub(crate) inner: ffi::IPLContext,
+
+    /// Default HRTFs by sampling rate and frame size, shared between all
+    /// handles of the same context.
+    pub(crate) default_hrtfs: Arc<Mutex<HashMap<(u32, u32), Hrtf>>>,
 }
 
 impl Context {
     /// Creates a context object. A context must be created before creating any
     /// other API objects.
+    ///
+    /// All allocations of Steam Audio are routed through Rust's global
+    /// allocator.
     pub fn new() -> Result<Self> {
+        Self::create(true)
+    }
+
+    /// Creates a context object which uses Steam Audio's internal allocator.
+    ///
+    /// This avoids the overhead of routing allocations through Rust's global
+    /// allocator, but Steam Audio's allocations are then not visible to it,
+    /// e.g. for memory profiling.
+    pub fn new_with_default_allocator() -> Result<Self> {
+        Self::create(false)
+    }
+
+    fn create(custom_allocator: bool) -> Result<Self> {
         unsafe extern "C" fn log_callback(
             level: ffi::IPLLogLevel,
             message: *const std::os::raw::c_char,
@@ -34,7 +60,10 @@ impl Context {
                 ffi::IPLLogLevel_IPL_LOGLEVEL_DEBUG => {
                     debug!(message);
                 }
-                _ => unreachable!(),
+                // Log levels added by newer versions of Steam Audio
+                _ => {
+                    warn!(level, message);
+                }
             }
         }
 
@@ -84,8 +113,8 @@ impl Context {
                 | ffi::STEAMAUDIO_VERSION_MINOR << 8
                 | ffi::STEAMAUDIO_VERSION_PATCH,
             logCallback: Some(log_callback),
-            allocateCallback: Some(allocate_callback),
-            freeCallback: Some(free_callback),
+            allocateCallback: custom_allocator.then_some(allocate_callback as _),
+            freeCallback: custom_allocator.then_some(free_callback as _),
             simdLevel: ffi::IPLSIMDLevel_IPL_SIMDLEVEL_AVX512,
             flags: 0,
         };
@@ -94,10 +123,27 @@ impl Context {
         unsafe {
             check(
                 ffi::iplContextCreate(&mut context_settings, &mut context),
-                Self { inner: context },
+                Self {
+                    inner: context,
+                    default_hrtfs: Default::default(),
+                },
             )
         }
     }
+
+    /// Returns the version (major, minor, patch) of Steam Audio the bindings
+    /// were generated for.
+    ///
+    /// Steam Audio doesn't report the version of the loaded library, instead
+    /// creating a context fails if the loaded library isn't compatible with
+    /// this version.
+    pub fn version() -> (u16, u16, u16) {
+        (
+            ffi::STEAMAUDIO_VERSION_MAJOR as u16,
+            ffi::STEAMAUDIO_VERSION_MINOR as u16,
+            ffi::STEAMAUDIO_VERSION_PATCH as u16,
+        )
+    }
 }
 
 impl Clone for Context {
@@ -106,7 +152,18 @@ impl Clone for Context {
             ffi::iplContextRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            default_hrtfs: self.default_hrtfs.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Context")
+            .field("inner", &self.inner)
+            .finish()
     }
 }
 
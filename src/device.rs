@@ -0,0 +1,400 @@
+use std::ffi::CStr;
+
+use crate::{
+    context::Context,
+    error::{check, Result},
+    ffi,
+};
+
+impl Context {
+    /// Enumerates the OpenCL devices matching the given settings available on
+    /// the system.
+    ///
+    /// OpenCL devices are required for GPU-accelerated simulation and
+    /// convolution.
+    pub fn open_cl_devices(&self, settings: OpenClDeviceSettings) -> Result<OpenClDeviceList> {
+        let mut device_settings = ffi::IPLOpenCLDeviceSettings {
+            type_: settings.device_type.into(),
+            numCUsToReserve: settings.reserved_compute_units as i32,
+            fractionCUsForIRUpdate: settings.ir_update_fraction,
+            requiresTAN: if settings.requires_true_audio_next {
+                ffi::IPLbool_IPL_TRUE
+            } else {
+                ffi::IPLbool_IPL_FALSE
+            },
+        };
+        let mut device_list = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplOpenCLDeviceListCreate(self.inner, &mut device_settings, &mut device_list),
+                OpenClDeviceList {
+                    inner: device_list,
+                    context: self.clone(),
+                },
+            )
+        }
+    }
+}
+
+/// Specifies requirements that an OpenCL device must meet in order to be
+/// considered when listing OpenCL devices.
+#[derive(Clone, Debug)]
+pub struct OpenClDeviceSettings {
+    /// The type of device.
+    pub device_type: OpenClDeviceType,
+
+    /// The number of GPU compute units (CUs) that should be reserved for use by
+    /// Steam Audio. If set to a non-zero value, then a GPU will be included in
+    /// the device list only if it can reserve at least this many CUs. Set to
+    /// 0 to indicate that Steam Audio can use the entire GPU, in which case
+    /// all available GPUs will be considered.
+    pub reserved_compute_units: u32,
+
+    /// The fraction of reserved CUs that should be used for impulse response
+    /// (IR) update. IR update includes: a) ray tracing using Radeon Rays to
+    /// simulate sound propagation, and/or b) pre-transformation of IRs for
+    /// convolution using TrueAudio Next.
+    pub ir_update_fraction: f32,
+
+    /// If `true`, then the GPU device must support TrueAudio Next.
+    pub requires_true_audio_next: bool,
+}
+
+impl Default for OpenClDeviceSettings {
+    fn default() -> Self {
+        Self {
+            device_type: OpenClDeviceType::Any,
+            reserved_compute_units: 0,
+            ir_update_fraction: 0.0,
+            requires_true_audio_next: false,
+        }
+    }
+}
+
+/// The types of OpenCL devices that can be enumerated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OpenClDeviceType {
+    /// List both CPU and GPU devices.
+    Any,
+
+    /// Only list CPU devices.
+    Cpu,
+
+    /// Only list GPU devices.
+    Gpu,
+}
+
+impl From<OpenClDeviceType> for ffi::IPLOpenCLDeviceType {
+    fn from(value: OpenClDeviceType) -> Self {
+        match value {
+            OpenClDeviceType::Any => ffi::IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_ANY,
+            OpenClDeviceType::Cpu => ffi::IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_CPU,
+            OpenClDeviceType::Gpu => ffi::IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_GPU,
+        }
+    }
+}
+
+/// Provides a list of OpenCL devices available on the user's system.
+pub struct OpenClDeviceList {
+    inner: ffi::IPLOpenCLDeviceList,
+
+    context: Context,
+}
+
+impl OpenClDeviceList {
+    /// Returns the number of devices in the list.
+    pub fn len(&self) -> usize {
+        unsafe { ffi::iplOpenCLDeviceListGetNumDevices(self.inner) as usize }
+    }
+
+    /// Returns `true` if no devices are available.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retrieves information about the device at the given index.
+    pub fn info(&self, index: usize) -> OpenClDeviceInfo {
+        assert!(index < self.len());
+
+        unsafe {
+            let mut device_desc: ffi::IPLOpenCLDeviceDesc = std::mem::zeroed();
+            ffi::iplOpenCLDeviceListGetDeviceDesc(self.inner, index as i32, &mut device_desc);
+
+            OpenClDeviceInfo {
+                name: CStr::from_ptr(device_desc.deviceName)
+                    .to_string_lossy()
+                    .into_owned(),
+                vendor: CStr::from_ptr(device_desc.deviceVendor)
+                    .to_string_lossy()
+                    .into_owned(),
+                version: CStr::from_ptr(device_desc.deviceVersion)
+                    .to_string_lossy()
+                    .into_owned(),
+                device_type: match device_desc.type_ {
+                    ffi::IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_CPU => OpenClDeviceType::Cpu,
+                    ffi::IPLOpenCLDeviceType_IPL_OPENCLDEVICETYPE_GPU => OpenClDeviceType::Gpu,
+                    _ => OpenClDeviceType::Any,
+                },
+                convolution_compute_units: device_desc.numConvolutionCUs as u32,
+                ir_update_compute_units: device_desc.numIRUpdateCUs as u32,
+                performance_score: device_desc.perfScore,
+            }
+        }
+    }
+
+    /// Creates the OpenCL device at the given index.
+    pub fn create_device(&self, index: usize) -> Result<OpenClDevice> {
+        assert!(index < self.len());
+
+        let mut device = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplOpenCLDeviceCreate(
+                    self.context.inner,
+                    self.inner,
+                    index as i32,
+                    &mut device,
+                ),
+                OpenClDevice { inner: device },
+            )
+        }
+    }
+}
+
+impl Clone for OpenClDeviceList {
+    fn clone(&self) -> Self {
+        unsafe {
+            ffi::iplOpenCLDeviceListRetain(self.inner);
+        }
+
+        Self {
+            inner: self.inner,
+            context: self.context.clone(),
+        }
+    }
+}
+
+impl Drop for OpenClDeviceList {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplOpenCLDeviceListRelease(&mut self.inner);
+        }
+    }
+}
+
+unsafe impl Send for OpenClDeviceList {}
+
+unsafe impl Sync for OpenClDeviceList {}
+
+/// Information about an OpenCL device.
+#[derive(Clone, Debug)]
+pub struct OpenClDeviceInfo {
+    /// Name of the device.
+    pub name: String,
+
+    /// Vendor of the device.
+    pub vendor: String,
+
+    /// Version of the device.
+    pub version: String,
+
+    /// Type of the device.
+    pub device_type: OpenClDeviceType,
+
+    /// Number of compute units reserved for convolution.
+    pub convolution_compute_units: u32,
+
+    /// Number of compute units reserved for IR update.
+    pub ir_update_compute_units: u32,
+
+    /// A relative performance score of the device, higher is better.
+    pub performance_score: f32,
+}
+
+/// Application-wide state for OpenCL.
+///
+/// An OpenCL device must be created before using any of Steam Audio's Radeon
+/// Rays or TrueAudio Next functionality.
+pub struct OpenClDevice {
+    pub(crate) inner: ffi::IPLOpenCLDevice,
+}
+
+impl OpenClDevice {
+    /// Creates a TrueAudio Next device, which is used for GPU-accelerated
+    /// convolution of reflections.
+    ///
+    /// The OpenCL device must have been listed with
+    /// [`OpenClDeviceSettings::requires_true_audio_next`] set.
+    pub fn create_true_audio_next_device(
+        &self,
+        frame_size: u32,
+        ir_size: u32,
+        order: u8,
+        maximum_sources: u32,
+    ) -> Result<TrueAudioNextDevice> {
+        let mut true_audio_next_device_settings = ffi::IPLTrueAudioNextDeviceSettings {
+            frameSize: frame_size as i32,
+            irSize: ir_size as i32,
+            order: order as i32,
+            maxSources: maximum_sources as i32,
+        };
+        let mut true_audio_next_device = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplTrueAudioNextDeviceCreate(
+                    self.inner,
+                    &mut true_audio_next_device_settings,
+                    &mut true_audio_next_device,
+                ),
+                TrueAudioNextDevice {
+                    inner: true_audio_next_device,
+                    open_cl_device: self.clone(),
+                },
+            )
+        }
+    }
+}
+
+impl OpenClDevice {
+    /// Creates a Radeon Rays device, which is used for GPU-accelerated ray
+    /// tracing, see [`SceneType::RadeonRays`].
+    ///
+    /// [`SceneType::RadeonRays`]: crate::scene::SceneType::RadeonRays
+    pub fn create_radeon_rays_device(&self) -> Result<RadeonRaysDevice> {
+        let mut radeon_rays_device = std::ptr::null_mut();
+
+        unsafe {
+            // There are no settings to configure yet.
+            let mut radeon_rays_device_settings: ffi::IPLRadeonRaysDeviceSettings =
+                std::mem::zeroed();
+            check(
+                ffi::iplRadeonRaysDeviceCreate(
+                    self.inner,
+                    &mut radeon_rays_device_settings,
+                    &mut radeon_rays_device,
+                ),
+                RadeonRaysDevice {
+                    inner: radeon_rays_device,
+                    open_cl_device: self.clone(),
+                },
+            )
+        }
+    }
+}
+
+impl Clone for OpenClDevice {
+    fn clone(&self) -> Self {
+        unsafe {
+            ffi::iplOpenCLDeviceRetain(self.inner);
+        }
+
+        Self { inner: self.inner }
+    }
+}
+
+impl std::fmt::Debug for OpenClDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenClDevice")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Drop for OpenClDevice {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplOpenCLDeviceRelease(&mut self.inner);
+        }
+    }
+}
+
+unsafe impl Send for OpenClDevice {}
+
+unsafe impl Sync for OpenClDevice {}
+
+/// Application-wide state for the TrueAudio Next convolution engine.
+///
+/// A TrueAudio Next device must be created before using the TrueAudio Next
+/// reflection effect.
+pub struct TrueAudioNextDevice {
+    pub(crate) inner: ffi::IPLTrueAudioNextDevice,
+    pub(crate) open_cl_device: OpenClDevice,
+}
+
+impl Clone for TrueAudioNextDevice {
+    fn clone(&self) -> Self {
+        unsafe {
+            ffi::iplTrueAudioNextDeviceRetain(self.inner);
+        }
+
+        Self {
+            inner: self.inner,
+            open_cl_device: self.open_cl_device.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for TrueAudioNextDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrueAudioNextDevice")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Drop for TrueAudioNextDevice {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplTrueAudioNextDeviceRelease(&mut self.inner);
+        }
+    }
+}
+
+unsafe impl Send for TrueAudioNextDevice {}
+
+unsafe impl Sync for TrueAudioNextDevice {}
+
+/// Application-wide state for the Radeon Rays ray tracer.
+///
+/// A Radeon Rays device must be created before using the Radeon Rays ray
+/// tracer for scenes and simulation.
+pub struct RadeonRaysDevice {
+    pub(crate) inner: ffi::IPLRadeonRaysDevice,
+    pub(crate) open_cl_device: OpenClDevice,
+}
+
+impl Clone for RadeonRaysDevice {
+    fn clone(&self) -> Self {
+        unsafe {
+            ffi::iplRadeonRaysDeviceRetain(self.inner);
+        }
+
+        Self {
+            inner: self.inner,
+            open_cl_device: self.open_cl_device.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for RadeonRaysDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadeonRaysDevice")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Drop for RadeonRaysDevice {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplRadeonRaysDeviceRelease(&mut self.inner);
+        }
+    }
+}
+
+unsafe impl Send for RadeonRaysDevice {}
+
+unsafe impl Sync for RadeonRaysDevice {}
@@ -1,13 +1,21 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
 use glam::Vec3;
+use tracing::error;
 
 use crate::{
+    ambisonics_channels,
     buffer::{Buffer, SpeakerLayout},
     context::Context,
-    error::check,
+    device::TrueAudioNextDevice,
+    error::{check, Error, Result},
     ffi,
     geometry::Orientation,
     hrtf::Hrtf,
-    simulation::Source,
+    simulation::{Simulator, Source, TransmissionType},
 };
 
 impl Context {
@@ -22,8 +30,9 @@ impl Context {
             samplingRate: sampling_rate as i32,
             frameSize: frame_size as i32,
         };
+        let channels = speaker_layout.channels();
         let mut panning_effect_settings = ffi::IPLPanningEffectSettings {
-            speakerLayout: speaker_layout.into(),
+            speakerLayout: (&speaker_layout).into(),
         };
         let mut panning_effect = std::ptr::null_mut();
 
@@ -37,6 +46,8 @@ impl Context {
                 ),
                 PanningEffect {
                     inner: panning_effect,
+                    frame_size,
+                    channels,
                 },
             )
         }
@@ -66,7 +77,8 @@ impl Context {
                 ),
                 BinauralEffect {
                     inner: binaural_effect,
-                    hrtf: hrtf.clone(),
+                    frame_size,
+                    hrtf: Arc::new(Mutex::new(hrtf.clone())),
                 },
             )
         }
@@ -85,7 +97,7 @@ impl Context {
             frameSize: frame_size as i32,
         };
         let mut virtual_surround_effect_settings = ffi::IPLVirtualSurroundEffectSettings {
-            speakerLayout: speaker_layout.into(),
+            speakerLayout: (&speaker_layout).into(),
             hrtf: hrtf.inner,
         };
         let mut virtual_surround_effect = std::ptr::null_mut();
@@ -100,7 +112,8 @@ impl Context {
                 ),
                 VirtualSurroundEffect {
                     inner: virtual_surround_effect,
-                    hrtf: hrtf.clone(),
+                    frame_size,
+                    hrtf: Arc::new(Mutex::new(hrtf.clone())),
                 },
             )
         }
@@ -132,6 +145,8 @@ impl Context {
                 ),
                 AmbisonicsEncodeEffect {
                     inner: ambisonics_encode_effect,
+                    frame_size,
+                    maximum_order,
                 },
             )
         }
@@ -150,7 +165,7 @@ impl Context {
             frameSize: frame_size as i32,
         };
         let mut ambisonics_panning_effect_settings = ffi::IPLAmbisonicsPanningEffectSettings {
-            speakerLayout: speaker_layout.into(),
+            speakerLayout: (&speaker_layout).into(),
             maxOrder: maximum_order as i32,
         };
         let mut ambisonics_panning_effect = std::ptr::null_mut();
@@ -165,6 +180,8 @@ impl Context {
                 ),
                 AmbisonicsPanningEffect {
                     inner: ambisonics_panning_effect,
+                    frame_size,
+                    maximum_order,
                 },
             )
         }
@@ -198,7 +215,9 @@ impl Context {
                 ),
                 AmbisonicsBinauralEffect {
                     inner: ambisonics_binaural_effect,
-                    hrtf: hrtf.clone(),
+                    frame_size,
+                    hrtf: Arc::new(Mutex::new(hrtf.clone())),
+                    maximum_order,
                 },
             )
         }
@@ -230,6 +249,8 @@ impl Context {
                 ),
                 AmbisonicsRotationEffect {
                     inner: ambisonics_rotation_effect,
+                    frame_size,
+                    maximum_order,
                 },
             )
         }
@@ -249,7 +270,7 @@ impl Context {
             frameSize: frame_size as i32,
         };
         let mut ambisonics_decode_effect_settings = ffi::IPLAmbisonicsDecodeEffectSettings {
-            speakerLayout: speaker_layout.into(),
+            speakerLayout: (&speaker_layout).into(),
             hrtf: hrtf.inner,
             maxOrder: maximum_order as i32,
         };
@@ -265,7 +286,9 @@ impl Context {
                 ),
                 AmbisonicsDecodeEffect {
                     inner: ambisonics_decode_effect,
-                    hrtf: hrtf.clone(),
+                    frame_size,
+                    hrtf: Arc::new(Mutex::new(hrtf.clone())),
+                    maximum_order,
                 },
             )
         }
@@ -297,25 +320,35 @@ impl Context {
                 ),
                 DirectEffect {
                     inner: direct_effect,
+                    frame_size,
                 },
             )
         }
     }
 
     /// Creates a reflection effect.
+    ///
+    /// `duration` is the length of the impulse response in seconds, and must
+    /// match the duration passed to [`Simulator::set_reflections`], otherwise
+    /// the convolution reads beyond the simulated impulse response. Applying
+    /// the effect fails if `duration` exceeds the maximum duration of the
+    /// simulator, see [`Context::create_reflection_effect_for`] to check this
+    /// when creating the effect.
     pub fn create_reflection_effect(
         &self,
         sampling_rate: u32,
         frame_size: u32,
         channels: u16,
+        duration: f32,
+        reflection_effect_type: ReflectionEffectType,
     ) -> crate::error::Result<ReflectionEffect> {
         let mut audio_settings = ffi::IPLAudioSettings {
             samplingRate: sampling_rate as i32,
             frameSize: frame_size as i32,
         };
         let mut reflection_effect_settings = ffi::IPLReflectionEffectSettings {
-            type_: ffi::IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_CONVOLUTION,
-            irSize: (2 * sampling_rate) as i32,
+            type_: (&reflection_effect_type).into(),
+            irSize: (duration * sampling_rate as f32).ceil() as i32,
             numChannels: channels as i32,
         };
         let mut reflection_effect = std::ptr::null_mut();
@@ -330,17 +363,59 @@ impl Context {
                 ),
                 ReflectionEffect {
                     inner: reflection_effect,
+                    frame_size,
+                    duration,
+                    ir_size: reflection_effect_settings.irSize,
+                    channels: reflection_effect_settings.numChannels,
+                    reflection_effect_type,
+                    error_logged: Default::default(),
                 },
             )
         }
     }
 
+    /// Creates a reflection effect for applying the sources of `simulator`,
+    /// with the sampling rate, frame size and reflection effect type the
+    /// simulator was created with.
+    ///
+    /// Returns [`Error::ExceedsMaximum`] if `duration` exceeds the maximum
+    /// duration of the simulator, see [`Context::create_reflection_effect`].
+    pub fn create_reflection_effect_for(
+        &self,
+        simulator: &Simulator,
+        channels: u16,
+        duration: f32,
+    ) -> crate::error::Result<ReflectionEffect> {
+        if duration > simulator.maximum_duration {
+            return Err(Error::ExceedsMaximum {
+                parameter: "duration",
+                value: duration,
+                maximum: simulator.maximum_duration,
+            });
+        }
+
+        self.create_reflection_effect(
+            simulator.sampling_rate(),
+            simulator.frame_size(),
+            channels,
+            duration,
+            simulator.reflection_effect_type.clone(),
+        )
+    }
+
     /// Creates a path effect.
+    ///
+    /// If `spatialize` is `true`, the path effect renders the pathing results
+    /// to the given speaker layout, or binaurally using the given HRTF,
+    /// instead of outputting Ambisonics.
     pub fn create_path_effect(
         &self,
         sampling_rate: u32,
         frame_size: u32,
         maximum_order: u8,
+        spatialize: bool,
+        speaker_layout: SpeakerLayout,
+        hrtf: &Hrtf,
     ) -> crate::error::Result<PathEffect> {
         let mut audio_settings = ffi::IPLAudioSettings {
             samplingRate: sampling_rate as i32,
@@ -348,13 +423,9 @@ impl Context {
         };
         let mut path_effect_settings = ffi::IPLPathEffectSettings {
             maxOrder: maximum_order as i32,
-            spatialize: 0,
-            speakerLayout: ffi::IPLSpeakerLayout {
-                type_: 0,
-                numSpeakers: 0,
-                speakers: std::ptr::null_mut(),
-            },
-            hrtf: std::ptr::null_mut(),
+            spatialize: spatialize.into(),
+            speakerLayout: (&speaker_layout).into(),
+            hrtf: hrtf.inner,
         };
         let mut path_effect = std::ptr::null_mut();
 
@@ -366,22 +437,124 @@ impl Context {
                     &mut path_effect_settings,
                     &mut path_effect,
                 ),
-                PathEffect { inner: path_effect },
+                PathEffect {
+                    inner: path_effect,
+                    frame_size,
+                    hrtf: hrtf.clone(),
+                },
             )
         }
     }
+
+    /// Creates the effects needed to render a point source to an Ambisonics
+    /// bus, see [`EffectSet`].
+    pub fn create_effect_set(
+        &self,
+        sampling_rate: u32,
+        frame_size: u32,
+        order: u8,
+    ) -> crate::error::Result<EffectSet> {
+        Ok(EffectSet {
+            direct: self.create_direct_effect(sampling_rate, frame_size, 1)?,
+            encode: self.create_ambisonics_encode_effect(sampling_rate, frame_size, order)?,
+            order,
+            direct_buffer: Buffer::new(1, frame_size),
+        })
+    }
+
+    /// Creates the effects needed to render a point source binaurally, see
+    /// [`BinauralEffectSet`].
+    pub fn create_binaural_effect_set(
+        &self,
+        hrtf: &Hrtf,
+        sampling_rate: u32,
+        frame_size: u32,
+    ) -> crate::error::Result<BinauralEffectSet> {
+        Ok(BinauralEffectSet {
+            direct: self.create_direct_effect(sampling_rate, frame_size, 1)?,
+            binaural: self.create_binaural_effect(hrtf, sampling_rate, frame_size)?,
+            direct_buffer: Buffer::new(1, frame_size),
+        })
+    }
+
+    /// Creates an Ambisonics bus for mixing multiple sources into a single
+    /// sound field, see [`AmbisonicsBus`].
+    pub fn create_ambisonics_bus(
+        &self,
+        sampling_rate: u32,
+        frame_size: u32,
+        order: u8,
+    ) -> crate::error::Result<AmbisonicsBus> {
+        let channels = ambisonics_channels(order);
+        Ok(AmbisonicsBus {
+            context: self.clone(),
+            encode: self.create_ambisonics_encode_effect(sampling_rate, frame_size, order)?,
+            order,
+            encode_buffer: Buffer::new(channels, frame_size),
+            mix_buffer: Buffer::new(channels, frame_size),
+        })
+    }
 }
 
-pub trait Effect<T> {
+/// Applies an effect to an audio buffer, using the given parameters.
+///
+/// All effects are created through [`Context`], e.g.
+/// [`Context::create_panning_effect`] or [`Context::create_direct_effect`].
+///
+/// Panics if the buffers don't have as many samples as the frame size the
+/// effect was created with.
+pub trait Effect<T>: Reset {
     fn apply(&self, params: T, in_: &Buffer, out: &mut Buffer);
+}
 
+/// Resets the internal processing state of an effect. Unlike [`Effect`] this
+/// doesn't depend on the parameters, so a list of different effects can be
+/// reset at once, e.g. when the listener is teleported.
+pub trait Reset {
     fn reset(&self);
 }
 
+/// Steam Audio reads and writes exactly one frame, buffers with fewer samples
+/// would be accessed out of bounds, the check is therefore kept in release
+/// builds.
+fn assert_frame_size(frame_size: u32, in_: &Buffer, out: &Buffer) {
+    assert_eq!(
+        in_.samples(),
+        frame_size,
+        "input buffer must have as many samples as the frame size of the effect"
+    );
+    assert_eq!(
+        out.samples(),
+        frame_size,
+        "output buffer must have as many samples as the frame size of the effect"
+    );
+}
+
+/// The binaural effect downmixes its input to mono and always renders to
+/// stereo, other channel counts would be accessed out of bounds.
+fn assert_binaural_channels(in_: &Buffer, out: &Buffer) {
+    assert!(
+        in_.channels() == 1 || in_.channels() == 2,
+        "binaural effect input must be mono or stereo"
+    );
+    assert_eq!(out.channels(), 2, "binaural effect output must be stereo");
+}
+
 /// Pans a single-channel point source to a multi-channel speaker layout based
 /// on the 3D position of the source relative to the listener.
 pub struct PanningEffect {
     inner: ffi::IPLPanningEffect,
+    frame_size: u32,
+
+    channels: u16,
+}
+
+impl PanningEffect {
+    /// Number of channels of the speaker layout the effect pans to, i.e. the
+    /// number of channels the output buffer must have.
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
 }
 
 /// Parameters for applying a panning effect to an audio buffer.
@@ -392,6 +565,10 @@ pub struct PanningEffectParams {
 
 impl Effect<PanningEffectParams> for PanningEffect {
     fn apply(&self, params: PanningEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert_eq!(in_.channels(), 1, "panning effect input must be mono");
+        debug_assert_eq!(out.channels(), self.channels);
+
         let mut params = ffi::IPLPanningEffectParams {
             direction: params.direction.into(),
         };
@@ -400,12 +577,14 @@ impl Effect<PanningEffectParams> for PanningEffect {
             ffi::iplPanningEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for PanningEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplPanningEffectReset(self.inner);
@@ -419,7 +598,20 @@ impl Clone for PanningEffect {
             ffi::iplPanningEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            channels: self.channels,
+        }
+    }
+}
+
+impl std::fmt::Debug for PanningEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PanningEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -439,11 +631,14 @@ unsafe impl Sync for PanningEffect {}
 /// source relative to the listener.
 ///
 /// The source audio can be 1- or 2-channel; in either case all input channels
-/// are spatialized from the same position.
+/// are spatialized from the same position. 2-channel input is downmixed to mono
+/// before spatialization, so stereo stems lose their stereo image. The output
+/// is always 2-channel.
 pub struct BinauralEffect {
     inner: ffi::IPLBinauralEffect,
+    frame_size: u32,
 
-    hrtf: Hrtf,
+    hrtf: Arc<Mutex<Hrtf>>,
 }
 
 /// Parameters for applying a binaural effect to an audio buffer.
@@ -456,7 +651,8 @@ pub struct BinauralEffectParams {
 
     /// Amount to blend input audio with spatialized audio. When set to 0,
     /// output audio is not spatialized at all and is close to input audio.
-    /// If set to 1, output audio is fully spatialized.
+    /// If set to 1, output audio is fully spatialized. Values outside of
+    /// [0, 1] are clamped.
     pub spatial_blend: f32,
 }
 
@@ -464,6 +660,7 @@ pub struct BinauralEffectParams {
 ///
 /// This is used when rendering a point source whose position relative to the
 /// listener is not contained in the measured HRTF data.
+#[derive(Copy, Clone, Debug)]
 pub enum HrtfInterpolation {
     /// Nearest-neighbor filtering, i.e., no interpolation.
     ///
@@ -489,26 +686,56 @@ impl From<HrtfInterpolation> for ffi::IPLHRTFInterpolation {
     }
 }
 
-impl Effect<BinauralEffectParams> for BinauralEffect {
-    fn apply(&self, params: BinauralEffectParams, in_: &Buffer, out: &mut Buffer) {
+impl BinauralEffect {
+    /// Replaces the HRTF used by subsequent applications of the effect and its
+    /// clones, without having to recreate the effect.
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        *self.hrtf.lock().unwrap() = hrtf.clone();
+    }
+
+    /// Applies the effect like [`Effect::apply`], and returns the delays (in
+    /// samples) of the peaks of the left and right HRIR used, i.e. the
+    /// interaural time difference for the direction, e.g. for custom
+    /// crossfeed or for validating the HRTF.
+    pub fn apply_with_peak_delays(
+        &self,
+        params: BinauralEffectParams,
+        in_: &Buffer,
+        out: &mut Buffer,
+    ) -> [f32; 2] {
+        assert_frame_size(self.frame_size, in_, out);
+        assert_binaural_channels(in_, out);
+
+        let hrtf = self.hrtf.lock().unwrap();
+        let mut peak_delays = [0.0; 2];
         let mut params = ffi::IPLBinauralEffectParams {
             direction: params.direction.into(),
             interpolation: params.interpolation.into(),
-            spatialBlend: params.spatial_blend,
-            hrtf: self.hrtf.inner,
-            peakDelays: std::ptr::null_mut(),
+            spatialBlend: params.spatial_blend.clamp(0.0, 1.0),
+            hrtf: hrtf.inner,
+            peakDelays: peak_delays.as_mut_ptr(),
         };
 
         unsafe {
             ffi::iplBinauralEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
+
+        peak_delays
+    }
+}
+
+impl Effect<BinauralEffectParams> for BinauralEffect {
+    fn apply(&self, params: BinauralEffectParams, in_: &Buffer, out: &mut Buffer) {
+        self.apply_with_peak_delays(params, in_, out);
     }
+}
 
+impl Reset for BinauralEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplBinauralEffectReset(self.inner);
@@ -524,11 +751,21 @@ impl Clone for BinauralEffect {
 
         Self {
             inner: self.inner,
+            frame_size: self.frame_size,
             hrtf: self.hrtf.clone(),
         }
     }
 }
 
+impl std::fmt::Debug for BinauralEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BinauralEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
+    }
+}
+
 impl Drop for BinauralEffect {
     fn drop(&mut self) {
         unsafe {
@@ -541,6 +778,43 @@ unsafe impl Send for BinauralEffect {}
 
 unsafe impl Sync for BinauralEffect {}
 
+/// The effect used to spatialize a point source.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Spatialization {
+    /// Spatialize using a [`BinauralEffect`].
+    Binaural,
+
+    /// Spatialize using the cheaper [`PanningEffect`].
+    Panning,
+}
+
+/// Spatializes the most important sources binaurally, and falls back to
+/// panning for the remaining sources, to cap CPU usage in crowded scenes.
+pub struct SpatializerPolicy {
+    /// Maximum number of sources spatialized using a binaural effect.
+    pub binaural_sources: usize,
+}
+
+impl SpatializerPolicy {
+    pub fn new(binaural_sources: usize) -> Self {
+        Self { binaural_sources }
+    }
+
+    /// Selects the spatialization for each source given its importance, e.g.
+    /// its loudness or inverse distance to the listener. The
+    /// `binaural_sources` most important sources are spatialized binaurally.
+    pub fn select(&self, importance: &[f32]) -> Vec<Spatialization> {
+        let mut order = (0..importance.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| importance[b].total_cmp(&importance[a]));
+
+        let mut spatialization = vec![Spatialization::Panning; importance.len()];
+        for &index in order.iter().take(self.binaural_sources) {
+            spatialization[index] = Spatialization::Binaural;
+        }
+        spatialization
+    }
+}
+
 /// Spatializes multi-channel speaker-based audio (e.g., stereo, quadraphonic,
 /// 5.1, or 7.1) using HRTF-based binaural rendering.
 ///
@@ -554,26 +828,38 @@ unsafe impl Sync for BinauralEffect {}
 /// reduce CPU usage, at the cost of spatialization accuracy.
 pub struct VirtualSurroundEffect {
     inner: ffi::IPLVirtualSurroundEffect,
+    frame_size: u32,
 
-    hrtf: Hrtf,
+    hrtf: Arc<Mutex<Hrtf>>,
+}
+
+impl VirtualSurroundEffect {
+    /// Replaces the HRTF used by subsequent applications of the effect and its
+    /// clones, without having to recreate the effect.
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        *self.hrtf.lock().unwrap() = hrtf.clone();
+    }
 }
 
 impl Effect<()> for VirtualSurroundEffect {
     fn apply(&self, _params: (), in_: &Buffer, out: &mut Buffer) {
-        let mut params = ffi::IPLVirtualSurroundEffectParams {
-            hrtf: self.hrtf.inner,
-        };
+        assert_frame_size(self.frame_size, in_, out);
+
+        let hrtf = self.hrtf.lock().unwrap();
+        let mut params = ffi::IPLVirtualSurroundEffectParams { hrtf: hrtf.inner };
 
         unsafe {
             ffi::iplVirtualSurroundEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for VirtualSurroundEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplVirtualSurroundEffectReset(self.inner);
@@ -589,11 +875,21 @@ impl Clone for VirtualSurroundEffect {
 
         Self {
             inner: self.inner,
+            frame_size: self.frame_size,
             hrtf: self.hrtf.clone(),
         }
     }
 }
 
+impl std::fmt::Debug for VirtualSurroundEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualSurroundEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
+    }
+}
+
 impl Drop for VirtualSurroundEffect {
     fn drop(&mut self) {
         unsafe {
@@ -614,6 +910,9 @@ unsafe impl Sync for VirtualSurroundEffect {}
 /// mixed to a single Ambisonics buffer before being spatialized.
 pub struct AmbisonicsEncodeEffect {
     inner: ffi::IPLAmbisonicsEncodeEffect,
+    frame_size: u32,
+
+    maximum_order: u8,
 }
 
 /// Parameters for applying an Ambisonics encode effect to an audio buffer.
@@ -622,31 +921,64 @@ pub struct AmbisonicsEncodeEffectParams {
     /// normalized; Steam Audio will automatically normalize this vector. If
     /// a zero-length vector is passed, the output will be order 0
     /// (omnidirectional).
+    ///
+    /// The vector is expressed in the listener's coordinate system, see
+    /// [`Context::calculate_relative_direction`], the difference of the world
+    /// space positions ignores the orientation of the listener.
     pub direction: Vec3,
 
     /// Ambisonic order of the output buffer. May be less than the \c maxOrder
     /// specified when creating the effect, in which case the effect will
     /// generate fewer output channels, reducing CPU usage.
     pub order: u8,
+
+    /// Gain applied to the encoded output, e.g. to attenuate sources before
+    /// mixing many of them into a single Ambisonics buffer. `None` leaves the
+    /// output unscaled.
+    pub gain: Option<f32>,
+}
+
+impl AmbisonicsEncodeEffectParams {
+    /// Encodes in the given direction and order, without applying a gain.
+    pub fn new(direction: Vec3, order: u8) -> Self {
+        Self {
+            direction,
+            order,
+            gain: None,
+        }
+    }
 }
 
 impl Effect<AmbisonicsEncodeEffectParams> for AmbisonicsEncodeEffect {
     fn apply(&self, params: AmbisonicsEncodeEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            params.order <= self.maximum_order,
+            "order exceeds the maximum order the effect was created with"
+        );
+
+        let gain = params.gain;
         let mut params = ffi::IPLAmbisonicsEncodeEffectParams {
             direction: params.direction.into(),
-            order: params.order as i32,
+            order: params.order.min(self.maximum_order) as i32,
         };
 
         unsafe {
             ffi::iplAmbisonicsEncodeEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
+
+        if let Some(gain) = gain {
+            out.scale(gain);
+        }
     }
+}
 
+impl Reset for AmbisonicsEncodeEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplAmbisonicsEncodeEffectReset(self.inner);
@@ -660,7 +992,20 @@ impl Clone for AmbisonicsEncodeEffect {
             ffi::iplAmbisonicsEncodeEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            maximum_order: self.maximum_order,
+        }
+    }
+}
+
+impl std::fmt::Debug for AmbisonicsEncodeEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmbisonicsEncodeEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -682,6 +1027,9 @@ unsafe impl Sync for AmbisonicsEncodeEffect {}
 /// approximate the Ambisonic sound field.
 pub struct AmbisonicsPanningEffect {
     inner: ffi::IPLAmbisonicsPanningEffect,
+    frame_size: u32,
+
+    maximum_order: u8,
 }
 
 /// Parameters for applying an Ambisonics panning effect to an audio buffer.
@@ -694,20 +1042,28 @@ pub struct AmbisonicsPanningEffectParams {
 
 impl Effect<AmbisonicsPanningEffectParams> for AmbisonicsPanningEffect {
     fn apply(&self, params: AmbisonicsPanningEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            params.order <= self.maximum_order,
+            "order exceeds the maximum order the effect was created with"
+        );
+
         let mut params = ffi::IPLAmbisonicsPanningEffectParams {
-            order: params.order as i32,
+            order: params.order.min(self.maximum_order) as i32,
         };
 
         unsafe {
             ffi::iplAmbisonicsPanningEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for AmbisonicsPanningEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplAmbisonicsPanningEffectReset(self.inner);
@@ -721,7 +1077,20 @@ impl Clone for AmbisonicsPanningEffect {
             ffi::iplAmbisonicsPanningEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            maximum_order: self.maximum_order,
+        }
+    }
+}
+
+impl std::fmt::Debug for AmbisonicsPanningEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmbisonicsPanningEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -744,8 +1113,10 @@ unsafe impl Sync for AmbisonicsPanningEffect {}
 /// increased CPU usage.
 pub struct AmbisonicsBinauralEffect {
     inner: ffi::IPLAmbisonicsBinauralEffect,
+    frame_size: u32,
 
-    hrtf: Hrtf,
+    hrtf: Arc<Mutex<Hrtf>>,
+    maximum_order: u8,
 }
 
 /// Parameters for applying an Ambisonics binaural effect to an audio buffer.
@@ -756,23 +1127,40 @@ pub struct AmbisonicsBinauralEffectParams {
     pub order: u8,
 }
 
+impl AmbisonicsBinauralEffect {
+    /// Replaces the HRTF used by subsequent applications of the effect and its
+    /// clones, without having to recreate the effect.
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        *self.hrtf.lock().unwrap() = hrtf.clone();
+    }
+}
+
 impl Effect<AmbisonicsBinauralEffectParams> for AmbisonicsBinauralEffect {
     fn apply(&self, params: AmbisonicsBinauralEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            params.order <= self.maximum_order,
+            "order exceeds the maximum order the effect was created with"
+        );
+
+        let hrtf = self.hrtf.lock().unwrap();
         let mut params = ffi::IPLAmbisonicsBinauralEffectParams {
-            hrtf: self.hrtf.inner,
-            order: params.order as i32,
+            hrtf: hrtf.inner,
+            order: params.order.min(self.maximum_order) as i32,
         };
 
         unsafe {
             ffi::iplAmbisonicsBinauralEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for AmbisonicsBinauralEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplAmbisonicsBinauralEffectReset(self.inner);
@@ -788,11 +1176,22 @@ impl Clone for AmbisonicsBinauralEffect {
 
         Self {
             inner: self.inner,
+            frame_size: self.frame_size,
             hrtf: self.hrtf.clone(),
+            maximum_order: self.maximum_order,
         }
     }
 }
 
+impl std::fmt::Debug for AmbisonicsBinauralEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmbisonicsBinauralEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
+    }
+}
+
 impl Drop for AmbisonicsBinauralEffect {
     fn drop(&mut self) {
         unsafe {
@@ -812,6 +1211,9 @@ unsafe impl Sync for AmbisonicsBinauralEffect {}
 /// listener’s orientation.
 pub struct AmbisonicsRotationEffect {
     inner: ffi::IPLAmbisonicsRotationEffect,
+    frame_size: u32,
+
+    maximum_order: u8,
 }
 
 /// Parameters for applying an Ambisonics rotation effect to an audio buffer.
@@ -825,23 +1227,53 @@ pub struct AmbisonicsRotationEffectParams {
     pub order: u8,
 }
 
+impl AmbisonicsRotationEffect {
+    /// Rotates a world space sound field into the space of the listener, so
+    /// that it follows head movement.
+    ///
+    /// Only the rotation of the listener is used. Steam Audio expects the
+    /// orientation of the listener itself, not its inverse, which is applied
+    /// internally.
+    pub fn apply_listener(&self, listener: Orientation, order: u8, in_: &Buffer, out: &mut Buffer) {
+        self.apply(
+            AmbisonicsRotationEffectParams {
+                orientation: Orientation {
+                    translation: Vec3::ZERO,
+                    rotation: listener.rotation,
+                },
+                order,
+            },
+            in_,
+            out,
+        );
+    }
+}
+
 impl Effect<AmbisonicsRotationEffectParams> for AmbisonicsRotationEffect {
     fn apply(&self, params: AmbisonicsRotationEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            params.order <= self.maximum_order,
+            "order exceeds the maximum order the effect was created with"
+        );
+
         let mut params = ffi::IPLAmbisonicsRotationEffectParams {
             orientation: params.orientation.into(),
-            order: params.order as i32,
+            order: params.order.min(self.maximum_order) as i32,
         };
 
         unsafe {
             ffi::iplAmbisonicsRotationEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for AmbisonicsRotationEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplAmbisonicsRotationEffectReset(self.inner);
@@ -855,7 +1287,20 @@ impl Clone for AmbisonicsRotationEffect {
             ffi::iplAmbisonicsRotationEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            maximum_order: self.maximum_order,
+        }
+    }
+}
+
+impl std::fmt::Debug for AmbisonicsRotationEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmbisonicsRotationEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -878,8 +1323,10 @@ unsafe impl Sync for AmbisonicsRotationEffect {}
 /// Ambisonics panning effect or an Ambisonics binaural effect.
 pub struct AmbisonicsDecodeEffect {
     inner: ffi::IPLAmbisonicsDecodeEffect,
+    frame_size: u32,
 
-    hrtf: Hrtf,
+    hrtf: Arc<Mutex<Hrtf>>,
+    maximum_order: u8,
 }
 
 /// Parameters for applying an Ambisonics decode effect to an audio buffer.
@@ -896,11 +1343,26 @@ pub struct AmbisonicsDecodeEffectParams {
     pub binaural: bool,
 }
 
+impl AmbisonicsDecodeEffect {
+    /// Replaces the HRTF used by subsequent applications of the effect and its
+    /// clones, without having to recreate the effect.
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        *self.hrtf.lock().unwrap() = hrtf.clone();
+    }
+}
+
 impl Effect<AmbisonicsDecodeEffectParams> for AmbisonicsDecodeEffect {
     fn apply(&self, params: AmbisonicsDecodeEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            params.order <= self.maximum_order,
+            "order exceeds the maximum order the effect was created with"
+        );
+
+        let hrtf = self.hrtf.lock().unwrap();
         let mut params = ffi::IPLAmbisonicsDecodeEffectParams {
-            order: params.order as i32,
-            hrtf: self.hrtf.inner,
+            order: params.order.min(self.maximum_order) as i32,
+            hrtf: hrtf.inner,
             orientation: params.orientation.into(),
             binaural: params.binaural.into(),
         };
@@ -909,12 +1371,14 @@ impl Effect<AmbisonicsDecodeEffectParams> for AmbisonicsDecodeEffect {
             ffi::iplAmbisonicsDecodeEffectApply(
                 self.inner,
                 &mut params,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for AmbisonicsDecodeEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplAmbisonicsDecodeEffectReset(self.inner);
@@ -930,11 +1394,22 @@ impl Clone for AmbisonicsDecodeEffect {
 
         Self {
             inner: self.inner,
+            frame_size: self.frame_size,
             hrtf: self.hrtf.clone(),
+            maximum_order: self.maximum_order,
         }
     }
 }
 
+impl std::fmt::Debug for AmbisonicsDecodeEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AmbisonicsDecodeEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
+    }
+}
+
 impl Drop for AmbisonicsDecodeEffect {
     fn drop(&mut self) {
         unsafe {
@@ -951,10 +1426,18 @@ unsafe impl Sync for AmbisonicsDecodeEffect {}
 /// direct path between a point source and the listener.
 pub struct DirectEffect {
     inner: ffi::IPLDirectEffect,
+    frame_size: u32,
 }
 
 impl Effect<&Source> for DirectEffect {
     fn apply(&self, params: &Source, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert_eq!(
+            params.simulator.frame_size(),
+            self.frame_size,
+            "effect must have the same frame size as the simulator of the source"
+        );
+
         unsafe {
             let mut simulation_outputs = std::mem::zeroed();
 
@@ -963,16 +1446,47 @@ impl Effect<&Source> for DirectEffect {
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
                 &mut simulation_outputs,
             );
-            simulation_outputs.direct.flags = params.inputs.borrow().directFlags;
+            let state = params.state.lock().unwrap();
+            simulation_outputs.direct.flags = state.inputs.directFlags;
+            simulation_outputs.direct.transmissionType = state.transmission_type.into();
+            drop(state);
             ffi::iplDirectEffectApply(
                 self.inner,
                 &mut simulation_outputs.direct,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
+                &mut out.inner,
+            );
+        }
+
+        let gain = f32::from_bits(params.gain.load(Ordering::Relaxed));
+        if gain != 1.0 {
+            out.scale(gain);
+        }
+    }
+}
+
+impl Effect<DirectEffectParams> for DirectEffect {
+    fn apply(&self, params: DirectEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+
+        let gain = params.gain;
+        let mut direct_effect_params: ffi::IPLDirectEffectParams = params.into();
+        unsafe {
+            ffi::iplDirectEffectApply(
+                self.inner,
+                &mut direct_effect_params,
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
+
+        if let Some(gain) = gain {
+            out.scale(gain);
+        }
     }
+}
 
+impl Reset for DirectEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplDirectEffectReset(self.inner);
@@ -986,7 +1500,19 @@ impl Clone for DirectEffect {
             ffi::iplDirectEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+        }
+    }
+}
+
+impl std::fmt::Debug for DirectEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DirectEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -1002,35 +1528,263 @@ unsafe impl Send for DirectEffect {}
 
 unsafe impl Sync for DirectEffect {}
 
+/// Parameters for applying a direct effect to an audio buffer without a
+/// simulator, e.g. for sources not backed by a simulator or for offline
+/// processing. Terms which are `None` are not applied.
+///
+/// The parameters are applied through the [`Effect`] implementation of
+/// [`DirectEffect`] for this type, like sources are applied through its
+/// implementation for [`Source`].
+#[derive(Clone, Debug, Default)]
+pub struct DirectEffectParams {
+    /// Distance attenuation factor.
+    pub distance_attenuation: Option<f32>,
+
+    /// Air absorption factors for low, middle, high frequencies.
+    pub air_absorption: Option<[f32; 3]>,
+
+    /// Attenuation factor due to source directivity.
+    pub directivity: Option<f32>,
+
+    /// Fraction of sound energy that is not occluded, between 0.0 (fully
+    /// occluded) and 1.0 (not occluded).
+    pub occlusion: Option<f32>,
+
+    /// Fraction of sound energy transmitted through occluding geometry at low,
+    /// middle, high frequencies, only applied along with occlusion.
+    pub transmission: Option<([f32; 3], TransmissionType)>,
+
+    /// Linear gain, applied after the other terms, see [`Source::set_gain`].
+    pub gain: Option<f32>,
+}
+
+impl From<DirectEffectParams> for ffi::IPLDirectEffectParams {
+    fn from(value: DirectEffectParams) -> Self {
+        let mut flags = 0;
+        if value.distance_attenuation.is_some() {
+            flags |= ffi::IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYDISTANCEATTENUATION;
+        }
+        if value.air_absorption.is_some() {
+            flags |= ffi::IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYAIRABSORPTION;
+        }
+        if value.directivity.is_some() {
+            flags |= ffi::IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYDIRECTIVITY;
+        }
+        if value.occlusion.is_some() {
+            flags |= ffi::IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYOCCLUSION;
+        }
+        if value.transmission.is_some() {
+            flags |= ffi::IPLDirectEffectFlags_IPL_DIRECTEFFECTFLAGS_APPLYTRANSMISSION;
+        }
+        let (transmission, transmission_type) = value.transmission.unwrap_or_default();
+
+        Self {
+            flags,
+            transmissionType: transmission_type.into(),
+            distanceAttenuation: value.distance_attenuation.unwrap_or(1.0),
+            airAbsorption: value.air_absorption.unwrap_or([1.0; 3]),
+            directivity: value.directivity.unwrap_or(1.0),
+            occlusion: value.occlusion.unwrap_or(1.0),
+            transmission,
+        }
+    }
+}
+
 /// Applies the result of physics-based reflections simulation to an audio
 /// buffer. The result is encoded in Ambisonics, and can be decoded using an
 /// Ambisonics decode effect
+///
+/// The output only contains the reflected sound, the effect can therefore be
+/// used without a direct effect, e.g. as a reverb send with the dry signal
+/// routed elsewhere.
 pub struct ReflectionEffect {
     inner: ffi::IPLReflectionEffect,
+    frame_size: u32,
+
+    duration: f32,
+    ir_size: i32,
+    channels: i32,
+    reflection_effect_type: ReflectionEffectType,
+
+    /// Whether an error of [`Effect::apply`] was logged, so that it's only
+    /// logged once instead of every frame.
+    error_logged: Arc<AtomicBool>,
 }
 
-impl Effect<&Source> for ReflectionEffect {
-    fn apply(&self, params: &Source, in_: &Buffer, out: &mut Buffer) {
+/// Type of reflection effect algorithm to use.
+#[derive(Clone, Default)]
+pub enum ReflectionEffectType {
+    /// Multi-channel convolution reverb. Reflections reaching the listener are
+    /// encoded in an impulse response (IR), which is a filter that records
+    /// each reflection as it arrives. This algorithm renders reflections with
+    /// the most detail, but may result in significant CPU usage.
+    #[default]
+    Convolution,
+
+    /// Multi-channel convolution reverb, using AMD TrueAudio Next for GPU
+    /// acceleration. This algorithm is similar to convolution, but uses the
+    /// GPU instead of the CPU for processing, allowing significantly more
+    /// sources to be processed.
+    TrueAudioNext {
+        device: TrueAudioNextDevice,
+
+        /// Index of the slot the source is assigned to on the device, only
+        /// used by reflection effects. Must be less than the maximum number of
+        /// sources the device was created with.
+        slot: u32,
+    },
+
+    /// Parametric (or artificial) reverb, using feedback delay networks. The
+    /// reflected sound field is reduced to a few numbers that describe how
+    /// reverb decays over time. This is the fastest algorithm, but does not
+    /// render the direction of reflections, and can also be applied with
+    /// authored reverb times, see [`ReverbZone`].
+    Parametric,
+}
+
+impl From<&ReflectionEffectType> for ffi::IPLReflectionEffectType {
+    fn from(value: &ReflectionEffectType) -> Self {
+        match value {
+            ReflectionEffectType::Convolution => {
+                ffi::IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_CONVOLUTION
+            }
+            ReflectionEffectType::TrueAudioNext { .. } => {
+                ffi::IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_TAN
+            }
+            ReflectionEffectType::Parametric => {
+                ffi::IPLReflectionEffectType_IPL_REFLECTIONEFFECTTYPE_PARAMETRIC
+            }
+        }
+    }
+}
+
+impl ReflectionEffect {
+    /// Returns the length of the impulse response in samples.
+    pub fn ir_size(&self) -> u32 {
+        self.ir_size as u32
+    }
+
+    /// Applies the reflection effect, returns [`Error::ChannelMismatch`] if the
+    /// input buffer isn't mono, or the output buffer doesn't have the number
+    /// of channels the effect was created with.
+    ///
+    /// Returns [`Error::ExceedsMaximum`] if the effect was created with a
+    /// longer duration than the maximum duration of the simulator of the
+    /// source, as the convolution would read beyond the simulated impulse
+    /// response, and [`Error::FrameSizeMismatch`] if the buffers don't have
+    /// as many samples as the frame size of the effect.
+    pub fn try_apply(&self, source: &Source, in_: &Buffer, out: &mut Buffer) -> Result<()> {
+        if self.duration > source.simulator.maximum_duration {
+            return Err(Error::ExceedsMaximum {
+                parameter: "duration",
+                value: self.duration,
+                maximum: source.simulator.maximum_duration,
+            });
+        }
+        for buffer in [in_, &*out] {
+            if buffer.samples() != self.frame_size {
+                return Err(Error::FrameSizeMismatch {
+                    expected: self.frame_size,
+                    actual: buffer.samples(),
+                });
+            }
+        }
+        if in_.channels() != 1 {
+            return Err(Error::ChannelMismatch {
+                expected: 1,
+                actual: in_.channels(),
+            });
+        }
+        if out.channels() != self.channels as u16 {
+            return Err(Error::ChannelMismatch {
+                expected: self.channels as u16,
+                actual: out.channels(),
+            });
+        }
+
         unsafe {
             let mut simulation_outputs = std::mem::zeroed();
 
             ffi::iplSourceGetOutputs(
-                params.inner,
+                source.inner,
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS,
                 &mut simulation_outputs,
             );
-            simulation_outputs.reflections.numChannels = 4;
-            simulation_outputs.reflections.irSize = 2 * 44100;
+            simulation_outputs.reflections.type_ = (&self.reflection_effect_type).into();
+            simulation_outputs.reflections.numChannels = self.channels;
+            simulation_outputs.reflections.irSize = self.ir_size;
+            if let ReflectionEffectType::TrueAudioNext { device, slot } =
+                &self.reflection_effect_type
+            {
+                simulation_outputs.reflections.tanDevice = device.inner;
+                simulation_outputs.reflections.tanSlot = *slot as i32;
+            }
             ffi::iplReflectionEffectApply(
                 self.inner,
                 &mut simulation_outputs.reflections,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
                 std::ptr::null_mut(),
             );
         }
+
+        Ok(())
+    }
+}
+
+/// Outputs silence if the effect can't be applied, e.g. if the channel counts
+/// of the buffers don't match, see [`ReflectionEffect::try_apply`] to handle
+/// the error instead. The first error is logged, and panics in debug builds.
+impl Effect<&Source> for ReflectionEffect {
+    fn apply(&self, params: &Source, in_: &Buffer, out: &mut Buffer) {
+        let result = self.try_apply(params, in_, out);
+        debug_assert!(
+            result.is_ok(),
+            "failed to apply reflection effect: {result:?}"
+        );
+
+        if let Err(error) = result {
+            if !self.error_logged.swap(true, Ordering::Relaxed) {
+                error!(%error, "failed to apply reflection effect, outputting silence");
+            }
+
+            for channel in &mut out.data {
+                channel.fill(0.0);
+            }
+        }
     }
+}
 
+impl Effect<&ReverbZone> for ReflectionEffect {
+    fn apply(&self, params: &ReverbZone, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+        debug_assert!(
+            matches!(
+                self.reflection_effect_type,
+                ReflectionEffectType::Parametric
+            ),
+            "reverb zones can only be applied by parametric reflection effects"
+        );
+
+        unsafe {
+            let mut reflection_effect_params: ffi::IPLReflectionEffectParams = std::mem::zeroed();
+            reflection_effect_params.type_ = (&self.reflection_effect_type).into();
+            reflection_effect_params.reverbTimes = params.reverb_times;
+            reflection_effect_params.numChannels = self.channels;
+            reflection_effect_params.irSize = self.ir_size;
+
+            ffi::iplReflectionEffectApply(
+                self.inner,
+                &mut reflection_effect_params,
+                &in_.inner as *const _ as *mut _,
+                &mut out.inner,
+                std::ptr::null_mut(),
+            );
+        }
+    }
+}
+
+impl Reset for ReflectionEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplReflectionEffectReset(self.inner);
@@ -1044,7 +1798,24 @@ impl Clone for ReflectionEffect {
             ffi::iplReflectionEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            duration: self.duration,
+            ir_size: self.ir_size,
+            channels: self.channels,
+            reflection_effect_type: self.reflection_effect_type.clone(),
+            error_logged: self.error_logged.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ReflectionEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReflectionEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -1065,27 +1836,57 @@ unsafe impl Sync for ReflectionEffect {}
 /// source to the listener are combined into an Ambisonic sound field.
 pub struct PathEffect {
     inner: ffi::IPLPathEffect,
+    frame_size: u32,
+
+    hrtf: Hrtf,
 }
 
-impl Effect<&Source> for PathEffect {
-    fn apply(&self, params: &Source, in_: &Buffer, out: &mut Buffer) {
+/// Parameters for applying a path effect to an audio buffer.
+pub struct PathEffectParams<'a> {
+    /// The source whose pathing simulation results are applied.
+    pub source: &'a Source,
+
+    /// Ambisonic order of the output buffer. May be less than the \c maxOrder
+    /// specified when creating the effect, in which case the effect will
+    /// generate fewer output channels, reducing CPU usage.
+    pub order: u8,
+
+    /// Whether to render binaurally or use panning, only used if the effect
+    /// was created with spatialization enabled.
+    pub binaural: bool,
+
+    /// The orientation of the listener, only used if the effect was created
+    /// with spatialization enabled.
+    pub listener: Orientation,
+}
+
+impl Effect<PathEffectParams<'_>> for PathEffect {
+    fn apply(&self, params: PathEffectParams, in_: &Buffer, out: &mut Buffer) {
+        assert_frame_size(self.frame_size, in_, out);
+
         unsafe {
             let mut simulation_outputs = std::mem::zeroed();
 
             ffi::iplSourceGetOutputs(
-                params.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
+                params.source.inner,
+                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_PATHING,
                 &mut simulation_outputs,
             );
+            simulation_outputs.pathing.order = params.order as i32;
+            simulation_outputs.pathing.binaural = params.binaural.into();
+            simulation_outputs.pathing.hrtf = self.hrtf.inner;
+            simulation_outputs.pathing.listener = params.listener.into();
             ffi::iplPathEffectApply(
                 self.inner,
                 &mut simulation_outputs.pathing,
-                std::mem::transmute(&in_.inner),
+                &in_.inner as *const _ as *mut _,
                 &mut out.inner,
             );
         }
     }
+}
 
+impl Reset for PathEffect {
     fn reset(&self) {
         unsafe {
             ffi::iplPathEffectReset(self.inner);
@@ -1099,7 +1900,20 @@ impl Clone for PathEffect {
             ffi::iplPathEffectRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            frame_size: self.frame_size,
+            hrtf: self.hrtf.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for PathEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PathEffect")
+            .field("inner", &self.inner)
+            .field("frame_size", &self.frame_size)
+            .finish()
     }
 }
 
@@ -1114,3 +1928,228 @@ impl Drop for PathEffect {
 unsafe impl Send for PathEffect {}
 
 unsafe impl Sync for PathEffect {}
+
+/// The direct and Ambisonics encode effects needed to render a point source to
+/// an Ambisonics bus, along with the intermediate buffer.
+///
+/// Creating effects calls into the context and allocates, and therefore
+/// shouldn't be done on the audio thread. An effect set can be created on
+/// another thread and then moved into the audio processing function.
+#[derive(Debug)]
+pub struct EffectSet {
+    pub direct: DirectEffect,
+    pub encode: AmbisonicsEncodeEffect,
+
+    order: u8,
+    direct_buffer: Buffer,
+}
+
+impl EffectSet {
+    /// Applies the simulated direct path of the source to the mono input, and
+    /// encodes the result in the given direction, relative to the listener.
+    pub fn apply(&mut self, source: &Source, direction: Vec3, in_: &Buffer, out: &mut Buffer) {
+        self.direct.apply(source, in_, &mut self.direct_buffer);
+        self.encode.apply(
+            AmbisonicsEncodeEffectParams::new(direction, self.order),
+            &self.direct_buffer,
+            out,
+        );
+    }
+}
+
+impl Reset for EffectSet {
+    fn reset(&self) {
+        self.direct.reset();
+        self.encode.reset();
+    }
+}
+
+/// The direct and binaural effects needed to render a point source to
+/// headphones, along with the intermediate buffer.
+///
+/// The direct effect applies whatever the source is configured for, e.g. only
+/// its directivity, to the mono input, and the binaural effect spatializes the
+/// result to the stereo output. Like [`EffectSet`], this should be created
+/// outside of the audio thread.
+#[derive(Debug)]
+pub struct BinauralEffectSet {
+    pub direct: DirectEffect,
+    pub binaural: BinauralEffect,
+
+    direct_buffer: Buffer,
+}
+
+impl BinauralEffectSet {
+    /// Applies the simulated direct path of the source to the mono input, and
+    /// spatializes the result to the stereo output in the given direction,
+    /// relative to the listener.
+    pub fn apply(
+        &mut self,
+        source: &Source,
+        params: BinauralEffectParams,
+        in_: &Buffer,
+        out: &mut Buffer,
+    ) {
+        self.direct.apply(source, in_, &mut self.direct_buffer);
+        self.binaural.apply(params, &self.direct_buffer, out);
+    }
+}
+
+impl Reset for BinauralEffectSet {
+    fn reset(&self) {
+        self.direct.reset();
+        self.binaural.reset();
+    }
+}
+
+/// Encodes multiple mono sources into a single Ambisonics sound field, which
+/// is accumulated until it is drained, e.g. once per frame before decoding.
+///
+/// The sources are summed without normalization, so that adding a source
+/// doesn't change the loudness of the others. Many loud sources can therefore
+/// exceed full scale, which is avoided by scaling each source with
+/// [`AmbisonicsBus::add_source_with_gain`], e.g. by `1 / sqrt(n)` for `n`
+/// uncorrelated sources.
+#[derive(Debug)]
+pub struct AmbisonicsBus {
+    context: Context,
+    pub encode: AmbisonicsEncodeEffect,
+
+    order: u8,
+    encode_buffer: Buffer,
+    mix_buffer: Buffer,
+}
+
+impl AmbisonicsBus {
+    /// Encodes the mono input in the given direction, relative to the
+    /// listener, and adds it to the sound field.
+    pub fn add_source(&mut self, direction: Vec3, in_: &Buffer) {
+        self.add_source_with_gain(direction, 1.0, in_);
+    }
+
+    /// Encodes the mono input in the given direction, relative to the
+    /// listener, scales it by the linear gain, and adds it to the sound field.
+    pub fn add_source_with_gain(&mut self, direction: Vec3, gain: f32, in_: &Buffer) {
+        self.encode.apply(
+            AmbisonicsEncodeEffectParams {
+                direction,
+                order: self.order,
+                gain: Some(gain),
+            },
+            in_,
+            &mut self.encode_buffer,
+        );
+
+        unsafe {
+            ffi::iplAudioBufferMix(
+                self.context.inner,
+                &mut self.encode_buffer.inner,
+                &mut self.mix_buffer.inner,
+            );
+        }
+    }
+
+    /// Writes the sound field accumulated since the last call to the output,
+    /// and clears it for the next frame.
+    pub fn drain(&mut self, out: &mut Buffer) {
+        for (channel, mix_channel) in out.data.iter_mut().zip(&mut self.mix_buffer.data) {
+            channel.copy_from_slice(mix_channel);
+            mix_channel.fill(0.0);
+        }
+    }
+}
+
+impl Reset for AmbisonicsBus {
+    fn reset(&self) {
+        self.encode.reset();
+    }
+}
+
+/// An authored region with fixed reverb, for places where simulating
+/// reflections is overkill. The reverb is applied by a parametric reflection
+/// effect, e.g. while the listener is inside the zone.
+#[derive(Copy, Clone, Debug)]
+pub struct ReverbZone {
+    /// Minimum corner of the axis-aligned bounds of the zone.
+    pub min: Vec3,
+
+    /// Maximum corner of the axis-aligned bounds of the zone.
+    pub max: Vec3,
+
+    /// Time (in seconds) for the reverb to decay by 60 dB at low, middle, high
+    /// frequencies.
+    pub reverb_times: [f32; 3],
+}
+
+impl ReverbZone {
+    /// Returns `true` if the position is within the bounds of the zone.
+    pub fn contains(&self, position: Vec3) -> bool {
+        position.cmpge(self.min).all() && position.cmple(self.max).all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binaural_channels() {
+        assert_binaural_channels(&Buffer::new(1, 1), &Buffer::new(2, 1));
+        assert_binaural_channels(&Buffer::new(2, 1), &Buffer::new(2, 1));
+    }
+
+    /// Only checks that stereo input is accepted, the downmix to mono is done
+    /// by Steam Audio, and therefore can't be checked under the mock feature.
+    #[test]
+    fn binaural_effect_applies_stereo_input() {
+        let context = Context::new().unwrap();
+        let hrtf = context.create_hrtf(48000, 64).unwrap();
+        let effect = context.create_binaural_effect(&hrtf, 48000, 64).unwrap();
+
+        let mut out = Buffer::new(2, 64);
+        effect.apply(
+            BinauralEffectParams {
+                direction: Vec3::NEG_Z,
+                interpolation: HrtfInterpolation::Nearest,
+                spatial_blend: 1.0,
+            },
+            &Buffer::new(2, 64),
+            &mut out,
+        );
+        assert_eq!(out.channels(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "binaural effect input must be mono or stereo")]
+    fn binaural_channels_rejects_multichannel_input() {
+        assert_binaural_channels(&Buffer::new(4, 1), &Buffer::new(2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "binaural effect output must be stereo")]
+    fn binaural_channels_rejects_mono_output() {
+        assert_binaural_channels(&Buffer::new(1, 1), &Buffer::new(1, 1));
+    }
+
+    #[test]
+    fn reflection_effect_duration_exceeds_simulator() {
+        let context = Context::new().unwrap();
+        let simulator = context
+            .create_simulator_with_settings(
+                crate::simulation::SimulationSettings::with_reflections(48000, 256),
+            )
+            .unwrap();
+
+        assert!(context
+            .create_reflection_effect_for(&simulator, 4, 1.0)
+            .is_ok());
+        assert!(matches!(
+            context.create_reflection_effect_for(&simulator, 4, 2.0),
+            Err(Error::ExceedsMaximum {
+                parameter: "duration",
+                value: 2.0,
+                maximum: 1.0,
+            })
+        ));
+    }
+}
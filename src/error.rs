@@ -8,6 +8,20 @@ pub enum Error {
     OutOfMemory,
     #[error("An error occurred while initializing an external dependency.")]
     Initialization,
+    #[error("The {parameter} {value} exceeds the maximum {maximum} the object was created with.")]
+    ExceedsMaximum {
+        parameter: &'static str,
+        value: f32,
+        maximum: f32,
+    },
+    #[error("The speaker layout has no speakers.")]
+    EmptySpeakerLayout,
+    #[error("Expected a buffer with {expected} channels, but got {actual} channels.")]
+    ChannelMismatch { expected: u16, actual: u16 },
+    #[error("Expected a buffer with {expected} samples, but got {actual} samples.")]
+    FrameSizeMismatch { expected: u32, actual: u32 },
+    #[error("Steam Audio returned an unknown status {0}.")]
+    Unknown(i32),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -18,6 +32,6 @@ pub(crate) fn check<T>(status: ffi::IPLerror, value: T) -> Result<T> {
         ffi::IPLerror_IPL_STATUS_FAILURE => Err(Error::Failure),
         ffi::IPLerror_IPL_STATUS_OUTOFMEMORY => Err(Error::OutOfMemory),
         ffi::IPLerror_IPL_STATUS_INITIALIZATION => Err(Error::Initialization),
-        _ => unreachable!(),
+        _ => Err(Error::Unknown(status as i32)),
     }
 }
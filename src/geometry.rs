@@ -1,4 +1,4 @@
-use glam::{Mat4, Quat, Vec3};
+use glam::{DVec3, Mat4, Quat, Vec3};
 
 use crate::{context::Context, ffi};
 
@@ -20,6 +20,31 @@ impl Context {
     }
 }
 
+/// Speed of sound in air in meters per second.
+pub const SPEED_OF_SOUND: f32 = 343.0;
+
+/// Calculates the Doppler factor of a moving source heard by a moving listener,
+/// with positions in meters and velocities in meters per second.
+///
+/// The factor is greater than 1.0 if source and listener approach each other,
+/// and less than 1.0 if they move apart, and can be used to resample the
+/// source, e.g. with `transform::doppler`.
+pub fn doppler_factor(
+    source_position: Vec3,
+    source_velocity: Vec3,
+    listener_position: Vec3,
+    listener_velocity: Vec3,
+    speed_of_sound: f32,
+) -> f32 {
+    let direction = (listener_position - source_position).normalize_or_zero();
+    // Sources approaching at or above the speed of sound would result in an
+    // infinite or negative factor.
+    let source_speed = source_velocity.dot(direction).min(speed_of_sound * 0.99);
+    let listener_speed = listener_velocity.dot(direction).min(speed_of_sound);
+
+    (speed_of_sound - listener_speed) / (speed_of_sound - source_speed)
+}
+
 impl From<ffi::IPLVector3> for Vec3 {
     fn from(value: ffi::IPLVector3) -> Self {
         Self {
@@ -122,6 +147,37 @@ pub struct Orientation {
     pub rotation: Quat,
 }
 
+impl Orientation {
+    /// Creates an orientation from a double precision world-space position,
+    /// relative to the given origin, e.g. the listener's position.
+    ///
+    /// The origin is subtracted in double precision before converting to
+    /// single precision, so that large worlds don't lose precision close to
+    /// the origin. The listener, all sources and the scene have to use the
+    /// same origin.
+    pub fn from_world(translation: DVec3, rotation: Quat, origin: DVec3) -> Self {
+        Self {
+            translation: (translation - origin).as_vec3(),
+            rotation,
+        }
+    }
+
+    /// Interpolates between two orientations, linearly for the translation and
+    /// spherically for the rotation.
+    ///
+    /// When transforms are updated less often than audio is processed, moving
+    /// the listener or sources in steps causes audible zipper noise, instead
+    /// the last two transforms can be interpolated, e.g. by setting the
+    /// interpolated orientation and running the direct simulation several
+    /// times between two updates.
+    pub fn slerp(a: Orientation, b: Orientation, t: f32) -> Orientation {
+        Orientation {
+            translation: a.translation.lerp(b.translation, t),
+            rotation: a.rotation.slerp(b.rotation, t),
+        }
+    }
+}
+
 impl From<Orientation> for ffi::IPLCoordinateSpace3 {
     fn from(value: Orientation) -> Self {
         Self {
@@ -132,3 +188,20 @@ impl From<Orientation> for ffi::IPLCoordinateSpace3 {
         }
     }
 }
+
+/// Decomposes the matrix into translation and rotation, scale is discarded.
+impl From<Mat4> for Orientation {
+    fn from(value: Mat4) -> Self {
+        let (_, rotation, translation) = value.to_scale_rotation_translation();
+        Self {
+            translation,
+            rotation,
+        }
+    }
+}
+
+impl From<Orientation> for Mat4 {
+    fn from(value: Orientation) -> Self {
+        Mat4::from_rotation_translation(value.rotation, value.translation)
+    }
+}
@@ -35,6 +35,24 @@ impl Context {
             )
         }
     }
+
+    /// Returns the default HRTF for the given sampling rate and frame size,
+    /// which is created on first use and shared by all callers afterwards, so
+    /// that multiple effects don't each pay the creation cost.
+    ///
+    /// Unlike [`Context::create_hrtf`], this function can be called from
+    /// multiple threads, as the creation is serialized, but it must still not
+    /// be called simultaneously with [`Context::create_hrtf`].
+    pub fn default_hrtf(&self, sampling_rate: u32, frame_size: u32) -> crate::error::Result<Hrtf> {
+        let mut default_hrtfs = self.default_hrtfs.lock().unwrap();
+        if let Some(hrtf) = default_hrtfs.get(&(sampling_rate, frame_size)) {
+            return Ok(hrtf.clone());
+        }
+
+        let hrtf = self.create_hrtf(sampling_rate, frame_size)?;
+        default_hrtfs.insert((sampling_rate, frame_size), hrtf.clone());
+        Ok(hrtf)
+    }
 }
 
 /// A Head-Related Transfer Function (HRTF). HRTFs describe how sound from
@@ -55,6 +73,12 @@ impl Clone for Hrtf {
     }
 }
 
+impl std::fmt::Debug for Hrtf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hrtf").field("inner", &self.inner).finish()
+    }
+}
+
 impl Drop for Hrtf {
     fn drop(&mut self) {
         unsafe {
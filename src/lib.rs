@@ -1,16 +1,37 @@
+//! Steam Audio for Rust.
+//!
+//! No features are enabled by default, the core (buffers, effects, HRTFs,
+//! scenes and simulation) doesn't depend on any audio library. Integrations are
+//! opt-in:
+//!
+//! - `rodio`: `transform` adapters which apply effects to `rodio` sources, and
+//!   a `spatializer` for binaural playback without wiring up effects manually.
+//! - `oddio`: `signal` adapters which apply effects to `oddio` signals.
+//! - `tokio`: asynchronous simulation runs on the blocking thread pool.
+//! - `mock`: stubs out all Steam Audio functions, neither the headers nor the
+//!   shared library are required, e.g. for testing without the SDK.
+
 pub mod buffer;
 pub mod context;
+pub mod device;
 pub mod effect;
 pub mod error;
 pub mod geometry;
 pub mod hrtf;
+pub mod probe;
 pub mod scene;
 pub mod simulation;
+pub mod wav;
 
+#[cfg(feature = "oddio")]
+pub mod signal;
+#[cfg(feature = "rodio")]
+pub mod spatializer;
 #[cfg(feature = "rodio")]
 pub mod transform;
 
 #[allow(
+    clippy::upper_case_acronyms,
     dead_code,
     non_camel_case_types,
     non_snake_case,
@@ -23,3 +44,15 @@ mod ffi {
 pub fn ambisonics_channels(order: u8) -> u16 {
     (order as u16 + 1).pow(2)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambisonics_channels_by_order() {
+        assert_eq!(ambisonics_channels(0), 1);
+        assert_eq!(ambisonics_channels(1), 4);
+        assert_eq!(ambisonics_channels(3), 16);
+    }
+}
@@ -0,0 +1,336 @@
+use glam::{Mat4, Vec3};
+
+use crate::{
+    context::Context,
+    error::{check, Result},
+    ffi,
+    scene::Scene,
+};
+
+impl Context {
+    /// Creates an empty probe batch.
+    pub fn create_probe_batch(&self) -> Result<ProbeBatch> {
+        let mut probe_batch = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplProbeBatchCreate(self.inner, &mut probe_batch),
+                ProbeBatch {
+                    inner: probe_batch,
+                    context: self.clone(),
+                },
+            )
+        }
+    }
+
+    /// Loads a probe batch previously saved with [`ProbeBatch::save`].
+    ///
+    /// The probe batch must be committed before it is used for simulation.
+    pub fn load_probe_batch(&self, data: &[u8]) -> Result<ProbeBatch> {
+        let serialized_object = SerializedObject::new(self, data)?;
+        let mut probe_batch = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplProbeBatchLoad(self.inner, serialized_object.inner, &mut probe_batch),
+                ProbeBatch {
+                    inner: probe_batch,
+                    context: self.clone(),
+                },
+            )
+        }
+    }
+}
+
+/// A batch of sound probes, along with any associated data.
+///
+/// Probes are points at which baked data, e.g. reflections or pathing, is
+/// stored. Probes are generated for a scene, added to a batch, and the batch is
+/// then baked and used for simulation.
+pub struct ProbeBatch {
+    pub(crate) inner: ffi::IPLProbeBatch,
+
+    context: Context,
+}
+
+impl ProbeBatch {
+    /// Generates probes within the volume given by the transform, which maps
+    /// a unit cube centered at the origin to the volume, and adds them to the
+    /// batch.
+    ///
+    /// The probe batch must be committed afterwards for the change to take
+    /// effect.
+    ///
+    /// The scene is ray traced while it is locked, like for a simulation run,
+    /// calling this within [`Scene::update`] therefore deadlocks.
+    pub fn generate_probes(
+        &mut self,
+        scene: &Scene,
+        transform: Mat4,
+        generation: ProbeGeneration,
+    ) -> Result<()> {
+        let (type_, spacing, height) = match generation {
+            ProbeGeneration::Centroid => (
+                ffi::IPLProbeGenerationType_IPL_PROBEGENERATIONTYPE_CENTROID,
+                0.0,
+                0.0,
+            ),
+            ProbeGeneration::UniformFloor { spacing, height } => (
+                ffi::IPLProbeGenerationType_IPL_PROBEGENERATIONTYPE_UNIFORMFLOOR,
+                spacing,
+                height,
+            ),
+        };
+        let mut probe_generation_params = ffi::IPLProbeGenerationParams {
+            type_,
+            spacing,
+            height,
+            transform: transform.into(),
+        };
+        let mut probe_array = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplProbeArrayCreate(self.context.inner, &mut probe_array),
+                (),
+            )?;
+            {
+                let _guard = scene.lock.read().unwrap();
+                ffi::iplProbeArrayGenerateProbes(
+                    probe_array,
+                    scene.inner,
+                    &mut probe_generation_params,
+                );
+            }
+            ffi::iplProbeBatchAddProbeArray(self.inner, probe_array);
+            ffi::iplProbeArrayRelease(&mut probe_array);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of probes in the batch.
+    pub fn num_probes(&self) -> usize {
+        unsafe { ffi::iplProbeBatchGetNumProbes(self.inner) as usize }
+    }
+
+    /// Commits all changes made to the probe batch since this function was
+    /// last called.
+    pub fn commit(&self) {
+        unsafe {
+            ffi::iplProbeBatchCommit(self.inner);
+        }
+    }
+
+    /// Saves the probe batch, including all baked data, so that it can be
+    /// loaded with [`Context::load_probe_batch`] instead of baking it again.
+    pub fn save(&self) -> Result<Vec<u8>> {
+        let serialized_object = SerializedObject::new(&self.context, &[])?;
+
+        unsafe {
+            ffi::iplProbeBatchSave(self.inner, serialized_object.inner);
+        }
+
+        Ok(serialized_object.data().to_vec())
+    }
+}
+
+impl Clone for ProbeBatch {
+    fn clone(&self) -> Self {
+        unsafe {
+            ffi::iplProbeBatchRetain(self.inner);
+        }
+
+        Self {
+            inner: self.inner,
+            context: self.context.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ProbeBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProbeBatch")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Drop for ProbeBatch {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplProbeBatchRelease(&mut self.inner);
+        }
+    }
+}
+
+unsafe impl Send for ProbeBatch {}
+
+unsafe impl Sync for ProbeBatch {}
+
+/// Algorithms for generating probes.
+#[derive(Copy, Clone, Debug)]
+pub enum ProbeGeneration {
+    /// Generates a single probe at the center of the volume.
+    Centroid,
+
+    /// Generates probes that are uniformly spaced, at a fixed height above
+    /// solid geometry. A probe will never be generated above another probe
+    /// unless there is a solid object between them.
+    UniformFloor {
+        /// Spacing (in meters) between two neighboring probes.
+        spacing: f32,
+
+        /// Height (in meters) above the floor at which probes will be
+        /// generated.
+        height: f32,
+    },
+}
+
+/// Identifies a layer of baked data within a probe batch, which sources use to
+/// look up the data they are simulated with.
+#[derive(Copy, Clone, Debug)]
+pub struct BakedDataIdentifier {
+    /// The kind of simulation data, e.g. reflections or pathing.
+    pub type_: BakedDataType,
+
+    /// The source and listener placement the data is baked for, e.g. reverb
+    /// at the listener.
+    pub variation: BakedDataVariation,
+}
+
+impl From<BakedDataIdentifier> for ffi::IPLBakedDataIdentifier {
+    fn from(value: BakedDataIdentifier) -> Self {
+        let (variation, center, radius) = match value.variation {
+            BakedDataVariation::Reverb => (
+                ffi::IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_REVERB,
+                Vec3::ZERO,
+                0.0,
+            ),
+            BakedDataVariation::StaticSource { position, radius } => (
+                ffi::IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_STATICSOURCE,
+                position,
+                radius,
+            ),
+            BakedDataVariation::StaticListener { position, radius } => (
+                ffi::IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_STATICLISTENER,
+                position,
+                radius,
+            ),
+            BakedDataVariation::Dynamic => (
+                ffi::IPLBakedDataVariation_IPL_BAKEDDATAVARIATION_DYNAMIC,
+                Vec3::ZERO,
+                0.0,
+            ),
+        };
+
+        Self {
+            type_: match value.type_ {
+                BakedDataType::Reflections => ffi::IPLBakedDataType_IPL_BAKEDDATATYPE_REFLECTIONS,
+                BakedDataType::Pathing => ffi::IPLBakedDataType_IPL_BAKEDDATATYPE_PATHING,
+            },
+            variation,
+            endpointInfluence: ffi::IPLSphere {
+                center: center.into(),
+                radius,
+            },
+        }
+    }
+}
+
+/// The kind of simulation data that is baked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BakedDataType {
+    /// Reflections, i.e. reverb or impulse responses.
+    Reflections,
+
+    /// Pathing, i.e. sound propagation paths between probes.
+    Pathing,
+}
+
+/// Ways of baking data for different source and listener placements.
+#[derive(Copy, Clone, Debug)]
+pub enum BakedDataVariation {
+    /// Reverb at the listener, assuming the source is at the listener.
+    Reverb,
+
+    /// Reflections from a static source, to any listener position.
+    StaticSource {
+        /// Position of the source.
+        position: Vec3,
+
+        /// Radius (in meters) around the source, only probes within it are
+        /// baked.
+        radius: f32,
+    },
+
+    /// Reflections from any source position, to a static listener.
+    StaticListener {
+        /// Position of the listener.
+        position: Vec3,
+
+        /// Radius (in meters) around the listener, only probes within it are
+        /// baked.
+        radius: f32,
+    },
+
+    /// Data for moving sources and listeners, used by pathing.
+    Dynamic,
+}
+
+/// A serialized representation of an API object, used to save and load probe
+/// batches.
+struct SerializedObject {
+    inner: ffi::IPLSerializedObject,
+}
+
+impl SerializedObject {
+    fn new(context: &Context, data: &[u8]) -> Result<Self> {
+        // Steam Audio only reads from the given data when loading, and
+        // allocates its own storage when saving into an empty object.
+        let mut serialized_object_settings = ffi::IPLSerializedObjectSettings {
+            data: if data.is_empty() {
+                std::ptr::null_mut()
+            } else {
+                data.as_ptr() as *mut _
+            },
+            size: data.len() as _,
+        };
+        let mut serialized_object = std::ptr::null_mut();
+
+        unsafe {
+            check(
+                ffi::iplSerializedObjectCreate(
+                    context.inner,
+                    &mut serialized_object_settings,
+                    &mut serialized_object,
+                ),
+                Self {
+                    inner: serialized_object,
+                },
+            )
+        }
+    }
+
+    fn data(&self) -> &[u8] {
+        unsafe {
+            let size = ffi::iplSerializedObjectGetSize(self.inner) as usize;
+            if size == 0 {
+                return &[];
+            }
+
+            std::slice::from_raw_parts(
+                ffi::iplSerializedObjectGetData(self.inner) as *const u8,
+                size,
+            )
+        }
+    }
+}
+
+impl Drop for SerializedObject {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::iplSerializedObjectRelease(&mut self.inner);
+        }
+    }
+}
@@ -1,64 +1,141 @@
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex, RwLock},
+    thread::{self, ThreadId},
+};
+
 use glam::Mat4;
 
 use crate::{
     context::Context,
+    device::RadeonRaysDevice,
     error::{check, Result},
     ffi,
 };
 
 impl Context {
-    /// Creates a scene.
+    /// Creates a scene using Steam Audio's built-in ray tracer.
     ///
     /// A scene does not store any geometry information on its own; for that you
     /// need to create one or more static meshes or instanced meshes and add
     /// them to the scene.
     pub fn create_scene(&self) -> Result<Scene> {
+        self.create_scene_with_type(SceneType::Default)
+    }
+
+    /// Creates a scene using the given ray tracer. Simulators using the scene
+    /// must be created with the same scene type, see
+    /// [`SimulationSettings::scene_type`].
+    ///
+    /// [`SimulationSettings::scene_type`]: crate::simulation::SimulationSettings::scene_type
+    pub fn create_scene_with_type(&self, scene_type: SceneType) -> Result<Scene> {
         let mut scene_settings = ffi::IPLSceneSettings {
-            type_: ffi::IPLSceneType_IPL_SCENETYPE_DEFAULT,
+            type_: (&scene_type).into(),
             closestHitCallback: None,
             anyHitCallback: None,
             batchedClosestHitCallback: None,
             batchedAnyHitCallback: None,
             userData: std::ptr::null_mut(),
             embreeDevice: std::ptr::null_mut(),
-            radeonRaysDevice: std::ptr::null_mut(),
+            radeonRaysDevice: match &scene_type {
+                SceneType::Default => std::ptr::null_mut(),
+                SceneType::RadeonRays(device) => device.inner,
+            },
         };
         let mut scene = std::ptr::null_mut();
 
         unsafe {
             check(
                 ffi::iplSceneCreate(self.inner, &mut scene_settings, &mut scene),
-                Scene { inner: scene },
+                Scene {
+                    inner: scene,
+                    meshes: Default::default(),
+                    lock: Default::default(),
+                },
             )
         }
     }
 }
 
+/// Ray tracer implementations used by scenes and simulators.
+#[derive(Clone, Default)]
+pub enum SceneType {
+    /// Steam Audio's built-in ray tracer, which supports multi-threading and
+    /// runs on all platforms.
+    #[default]
+    Default,
+
+    /// The AMD Radeon Rays ray tracer, which uses the GPU through OpenCL, see
+    /// [`OpenClDevice::create_radeon_rays_device`].
+    ///
+    /// [`OpenClDevice::create_radeon_rays_device`]: crate::device::OpenClDevice::create_radeon_rays_device
+    RadeonRays(RadeonRaysDevice),
+}
+
+impl From<&SceneType> for ffi::IPLSceneType {
+    fn from(value: &SceneType) -> Self {
+        match value {
+            SceneType::Default => ffi::IPLSceneType_IPL_SCENETYPE_DEFAULT,
+            SceneType::RadeonRays(_) => ffi::IPLSceneType_IPL_SCENETYPE_RADEONRAYS,
+        }
+    }
+}
+
 /// A 3D scene, which can contain geometry objects that can interact with
 /// acoustic rays. The scene object itself doesn't contain any geometry, but is
 /// a container for \c IPLStaticMesh and \c IPLInstancedMesh objects, which
 /// do contain geometry.
 pub struct Scene {
     pub(crate) inner: ffi::IPLScene,
+
+    meshes: Arc<Mutex<SceneMeshes>>,
+
+    /// Held for reading during simulation runs, and for writing by
+    /// [`Scene::update`], shared between all handles of the same scene.
+    pub(crate) lock: Arc<RwLock<()>>,
+}
+
+/// Bookkeeping of the meshes currently added to a scene, shared between all
+/// handles of the same scene.
+#[derive(Default)]
+struct SceneMeshes {
+    static_meshes: Vec<ffi::IPLStaticMesh>,
+    instanced_meshes: Vec<ffi::IPLInstancedMesh>,
+
+    /// Whether meshes were added or removed since the last commit.
+    dirty: bool,
+
+    /// Whether the scene is committed automatically before the next
+    /// simulation run.
+    auto_commit: bool,
+
+    /// The thread running [`Scene::update`], if any.
+    updating: Option<ThreadId>,
 }
 
 impl Scene {
-    pub fn create_static_mesh(
+    /// Creates a static mesh.
+    ///
+    /// Indices can be either `u16` or `u32`, `u16` indices are widened
+    /// internally.
+    pub fn create_static_mesh<I: Index>(
         &self,
-        indices: &[[u32; 3]],
+        indices: &[[I; 3]],
         positions: &[[f32; 3]],
         material_indices: &[u32],
         materials: &[Material],
     ) -> Result<StaticMesh> {
+        let indices = I::widen(indices);
+
         unsafe {
             let mut static_mesh_settings = ffi::IPLStaticMeshSettings {
                 numVertices: positions.len() as i32,
                 numTriangles: indices.len() as i32,
                 numMaterials: materials.len() as i32,
-                vertices: std::mem::transmute(positions.as_ptr()),
-                triangles: std::mem::transmute(indices.as_ptr()),
-                materialIndices: std::mem::transmute(material_indices.as_ptr()),
-                materials: std::mem::transmute(materials.as_ptr()),
+                vertices: positions.as_ptr() as *mut ffi::IPLVector3,
+                triangles: indices.as_ptr() as *mut ffi::IPLTriangle,
+                materialIndices: material_indices.as_ptr() as *mut i32,
+                materials: materials.as_ptr() as *mut ffi::IPLMaterial,
             };
             let mut static_mesh = std::ptr::null_mut();
 
@@ -72,6 +149,27 @@ impl Scene {
         }
     }
 
+    /// Creates a static mesh, adds it to the scene and commits the scene.
+    ///
+    /// The mesh stays in the scene for as long as the returned handle is alive,
+    /// dropping it removes the mesh and commits the scene again. Both are done
+    /// within [`Scene::update`], and therefore wait for running simulations.
+    /// Within [`Scene::update`] on the same thread, the scene is only committed
+    /// once the outer update returns.
+    pub fn add_static_mesh<I: Index>(
+        &self,
+        indices: &[[I; 3]],
+        positions: &[[f32; 3]],
+        material_indices: &[u32],
+        materials: &[Material],
+    ) -> Result<SceneMeshHandle> {
+        let mut static_mesh =
+            self.create_static_mesh(indices, positions, material_indices, materials)?;
+        self.update(|_| static_mesh.set_visible(true));
+
+        Ok(SceneMeshHandle { static_mesh })
+    }
+
     pub fn create_instanced_mesh(&self, scene: &Scene, transform: Mat4) -> Result<InstancedMesh> {
         let mut instanced_mesh_settings = ffi::IPLInstancedMeshSettings {
             subScene: scene.inner,
@@ -90,16 +188,108 @@ impl Scene {
                     inner: instanced_mesh,
                     scene: self.clone(),
                     sub_scene: scene.clone(),
+                    transform,
                 },
             )
         }
     }
 
+    /// Removes all static and instanced meshes from the scene, e.g. when
+    /// unloading a level.
+    ///
+    /// The scene must be committed afterwards for the change to take effect.
+    pub fn clear_meshes(&self) {
+        let mut meshes = self.meshes.lock().unwrap();
+        for mut static_mesh in meshes.static_meshes.drain(..) {
+            unsafe {
+                ffi::iplStaticMeshRemove(static_mesh, self.inner);
+                ffi::iplStaticMeshRelease(&mut static_mesh);
+            }
+        }
+        for mut instanced_mesh in meshes.instanced_meshes.drain(..) {
+            unsafe {
+                ffi::iplInstancedMeshRemove(instanced_mesh, self.inner);
+                ffi::iplInstancedMeshRelease(&mut instanced_mesh);
+            }
+        }
+        meshes.dirty = true;
+    }
+
+    /// Returns the number of static and instanced meshes currently added to
+    /// the scene.
+    pub fn mesh_count(&self) -> usize {
+        let meshes = self.meshes.lock().unwrap();
+        meshes.static_meshes.len() + meshes.instanced_meshes.len()
+    }
+
+    /// Returns `true` if meshes were added or removed since the last commit.
+    pub fn is_dirty(&self) -> bool {
+        self.meshes.lock().unwrap().dirty
+    }
+
+    /// Enables or disables committing the scene automatically before the next
+    /// simulation run of a simulator using this scene, if meshes were added or
+    /// removed since the last commit.
+    pub fn set_auto_commit(&self, auto_commit: bool) {
+        self.meshes.lock().unwrap().auto_commit = auto_commit;
+    }
+
+    /// Commits the scene if meshes were added or removed since the last commit
+    /// and auto commit is enabled. Returns `true` if the scene was committed.
+    ///
+    /// Commits through [`Scene::update`], and therefore must not be called
+    /// while the scene is locked for a simulation run.
+    pub(crate) fn auto_commit(&self) -> bool {
+        let meshes = self.meshes.lock().unwrap();
+        if !(meshes.dirty && meshes.auto_commit) {
+            return false;
+        }
+        drop(meshes);
+
+        self.update(|_| ());
+        true
+    }
+
+    /// Applies several changes to the scene, e.g. toggling the visibility of
+    /// meshes, and commits them, while no simulation using this scene is
+    /// running, so that simulations on other threads never see a partially
+    /// updated scene.
+    ///
+    /// Waits for running simulations to finish, and blocks simulations until
+    /// the scene is committed, the lock is shared by all simulators using this
+    /// scene. Running a simulation using this scene within the function
+    /// therefore deadlocks.
+    ///
+    /// Nested updates on the same thread, including adding meshes with
+    /// [`Scene::add_static_mesh`] and dropping a [`SceneMeshHandle`], don't
+    /// lock the scene again, their changes are committed once when the
+    /// outermost update returns. Updating the scene from another thread within
+    /// the function deadlocks.
+    pub fn update<R>(&self, function: impl FnOnce(&Scene) -> R) -> R {
+        let thread = thread::current().id();
+        if self.meshes.lock().unwrap().updating == Some(thread) {
+            return function(self);
+        }
+
+        let _guard = self.lock.write().unwrap();
+        self.meshes.lock().unwrap().updating = Some(thread);
+        let result = function(self);
+        self.meshes.lock().unwrap().updating = None;
+        self.commit();
+        result
+    }
+
     /// Commits any changes to the scene.
+    ///
+    /// Adding or removing meshes only takes effect once the scene is committed,
+    /// but committing while a simulation using this scene runs on another
+    /// thread is a data race, see [`Scene::update`].
     pub fn commit(&self) {
         unsafe {
             ffi::iplSceneCommit(self.inner);
         }
+
+        self.meshes.lock().unwrap().dirty = false;
     }
 }
 
@@ -109,12 +299,38 @@ impl Clone for Scene {
             ffi::iplSceneRetain(self.inner);
         }
 
-        Self { inner: self.inner }
+        Self {
+            inner: self.inner,
+            meshes: self.meshes.clone(),
+            lock: self.lock.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Scene {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scene").field("inner", &self.inner).finish()
     }
 }
 
 impl Drop for Scene {
     fn drop(&mut self) {
+        // Release the references held for added meshes once the last handle of
+        // the scene goes away.
+        if let Some(meshes) = Arc::get_mut(&mut self.meshes) {
+            let meshes = meshes.get_mut().unwrap();
+            for static_mesh in &mut meshes.static_meshes {
+                unsafe {
+                    ffi::iplStaticMeshRelease(static_mesh);
+                }
+            }
+            for instanced_mesh in &mut meshes.instanced_meshes {
+                unsafe {
+                    ffi::iplInstancedMeshRelease(instanced_mesh);
+                }
+            }
+        }
+
         unsafe {
             ffi::iplSceneRelease(&mut self.inner);
         }
@@ -137,14 +353,32 @@ pub struct StaticMesh {
 
 impl StaticMesh {
     /// Add or removes a static mesh from a scene.
+    ///
+    /// The scene must be committed afterwards for the change to take effect.
+    /// If simulations run on other threads, this should be done within
+    /// [`Scene::update`].
     pub fn set_visible(&mut self, visible: bool) {
+        let mut meshes = self.scene.meshes.lock().unwrap();
+        let index = meshes
+            .static_meshes
+            .iter()
+            .position(|&static_mesh| static_mesh == self.inner);
         unsafe {
-            if visible {
-                ffi::iplStaticMeshAdd(self.inner, self.scene.inner)
-            } else {
-                ffi::iplStaticMeshRemove(self.inner, self.scene.inner)
+            match (visible, index) {
+                (true, None) => {
+                    ffi::iplStaticMeshAdd(self.inner, self.scene.inner);
+                    meshes
+                        .static_meshes
+                        .push(ffi::iplStaticMeshRetain(self.inner));
+                }
+                (false, Some(index)) => {
+                    ffi::iplStaticMeshRemove(self.inner, self.scene.inner);
+                    ffi::iplStaticMeshRelease(&mut meshes.static_meshes.swap_remove(index));
+                }
+                _ => return,
             }
         }
+        meshes.dirty = true;
     }
 }
 
@@ -161,6 +395,14 @@ impl Clone for StaticMesh {
     }
 }
 
+impl std::fmt::Debug for StaticMesh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticMesh")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl Drop for StaticMesh {
     fn drop(&mut self) {
         unsafe {
@@ -173,6 +415,30 @@ unsafe impl Send for StaticMesh {}
 
 unsafe impl Sync for StaticMesh {}
 
+/// A static mesh which is part of a scene for as long as the handle is alive,
+/// see [`Scene::add_static_mesh`].
+///
+/// Dropping handles within [`Scene::update`] removes their meshes with a single
+/// commit once the update returns.
+#[derive(Debug)]
+pub struct SceneMeshHandle {
+    static_mesh: StaticMesh,
+}
+
+impl SceneMeshHandle {
+    /// Returns the static mesh.
+    pub fn static_mesh(&self) -> &StaticMesh {
+        &self.static_mesh
+    }
+}
+
+impl Drop for SceneMeshHandle {
+    fn drop(&mut self) {
+        let scene = self.static_mesh.scene.clone();
+        scene.update(|_| self.static_mesh.set_visible(false));
+    }
+}
+
 /// A triangle mesh that can be moved (translated), rotated, or scaled, but
 /// cannot deform. Portions of a scene that undergo rigid-body motion can be
 /// represented as instanced meshes. An instanced mesh is essentially a
@@ -186,18 +452,37 @@ pub struct InstancedMesh {
 
     scene: Scene,
     sub_scene: Scene,
+    transform: Mat4,
 }
 
 impl InstancedMesh {
     /// Add or removes an instanced mesh from a scene.
+    ///
+    /// The scene must be committed afterwards for the change to take effect.
+    /// If simulations run on other threads, this should be done within
+    /// [`Scene::update`].
     pub fn set_visible(&mut self, visible: bool) {
+        let mut meshes = self.scene.meshes.lock().unwrap();
+        let index = meshes
+            .instanced_meshes
+            .iter()
+            .position(|&instanced_mesh| instanced_mesh == self.inner);
         unsafe {
-            if visible {
-                ffi::iplInstancedMeshAdd(self.inner, self.scene.inner)
-            } else {
-                ffi::iplInstancedMeshRemove(self.inner, self.scene.inner)
+            match (visible, index) {
+                (true, None) => {
+                    ffi::iplInstancedMeshAdd(self.inner, self.scene.inner);
+                    meshes
+                        .instanced_meshes
+                        .push(ffi::iplInstancedMeshRetain(self.inner));
+                }
+                (false, Some(index)) => {
+                    ffi::iplInstancedMeshRemove(self.inner, self.scene.inner);
+                    ffi::iplInstancedMeshRelease(&mut meshes.instanced_meshes.swap_remove(index));
+                }
+                _ => return,
             }
         }
+        meshes.dirty = true;
     }
 
     /// Updates the local-to-world transform of an instanced mesh within its
@@ -209,6 +494,13 @@ impl InstancedMesh {
         unsafe {
             ffi::iplInstancedMeshUpdateTransform(self.inner, self.scene.inner, transform.into());
         }
+        self.transform = transform;
+    }
+
+    /// Returns the transform last set through this handle, either on creation
+    /// or with [`InstancedMesh::set_transform`].
+    pub fn transform(&self) -> Mat4 {
+        self.transform
     }
 }
 
@@ -222,10 +514,19 @@ impl Clone for InstancedMesh {
             inner: self.inner,
             scene: self.scene.clone(),
             sub_scene: self.sub_scene.clone(),
+            transform: self.transform,
         }
     }
 }
 
+impl std::fmt::Debug for InstancedMesh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstancedMesh")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl Drop for InstancedMesh {
     fn drop(&mut self) {
         unsafe {
@@ -238,6 +539,78 @@ unsafe impl Send for InstancedMesh {}
 
 unsafe impl Sync for InstancedMesh {}
 
+/// An integer type which can be used for the vertex indices of a mesh.
+pub trait Index: Copy {
+    /// Widens the triangle indices to `u32`, as required by Steam Audio.
+    fn widen(indices: &[[Self; 3]]) -> Cow<'_, [[u32; 3]]>;
+}
+
+impl Index for u16 {
+    fn widen(indices: &[[Self; 3]]) -> Cow<'_, [[u32; 3]]> {
+        Cow::Owned(
+            indices
+                .iter()
+                .map(|triangle| triangle.map(u32::from))
+                .collect(),
+        )
+    }
+}
+
+impl Index for u32 {
+    fn widen(indices: &[[Self; 3]]) -> Cow<'_, [[u32; 3]]> {
+        Cow::Borrowed(indices)
+    }
+}
+
+/// Triangle mesh data in the layout expected by [`Scene::create_static_mesh`],
+/// e.g. converted from the meshes of a game engine.
+#[derive(Clone, Debug, Default)]
+pub struct StaticMeshData {
+    pub indices: Vec<[u32; 3]>,
+    pub positions: Vec<[f32; 3]>,
+    pub material_indices: Vec<u32>,
+}
+
+impl StaticMeshData {
+    /// Converts a triangle list, where every three consecutive indices form a
+    /// triangle, with per-vertex material indices. Each triangle uses the
+    /// material of its first vertex.
+    ///
+    /// Returns `None` if the number of indices isn't a multiple of three, an
+    /// index is out of range, or the number of material indices doesn't match
+    /// the number of positions.
+    pub fn from_triangle_list<I: Index + Into<u32>>(
+        indices: &[I],
+        positions: &[[f32; 3]],
+        vertex_material_indices: &[u32],
+    ) -> Option<Self> {
+        if !indices.len().is_multiple_of(3) || vertex_material_indices.len() != positions.len() {
+            return None;
+        }
+
+        let mut triangles = Vec::with_capacity(indices.len() / 3);
+        let mut material_indices = Vec::with_capacity(indices.len() / 3);
+        for triangle in indices.chunks_exact(3) {
+            let triangle: [u32; 3] = [triangle[0].into(), triangle[1].into(), triangle[2].into()];
+            if triangle
+                .iter()
+                .any(|&index| index as usize >= positions.len())
+            {
+                return None;
+            }
+
+            material_indices.push(vertex_material_indices[triangle[0] as usize]);
+            triangles.push(triangle);
+        }
+
+        Some(Self {
+            indices: triangles,
+            positions: positions.to_vec(),
+            material_indices,
+        })
+    }
+}
+
 /// The acoustic properties of a surface.
 ///
 /// You can specify the acoustic material properties of each triangle, although
@@ -246,19 +619,9 @@ unsafe impl Sync for InstancedMesh {}
 /// The acoustic material properties are specified for three frequency bands
 /// with center frequencies of 400 Hz, 2.5 KHz, and 15 KHz.
 ///
-/// Below are the acoustic material properties for a few standard materials.
-///
-/// {"generic",{0.10f,0.20f,0.30f,0.05f,0.100f,0.050f,0.030f}}
-/// {"brick",{0.03f,0.04f,0.07f,0.05f,0.015f,0.015f,0.015f}}
-/// {"concrete",{0.05f,0.07f,0.08f,0.05f,0.015f,0.002f,0.001f}}
-/// {"ceramic",{0.01f,0.02f,0.02f,0.05f,0.060f,0.044f,0.011f}}
-/// {"gravel",{0.60f,0.70f,0.80f,0.05f,0.031f,0.012f,0.008f}},
-/// {"carpet",{0.24f,0.69f,0.73f,0.05f,0.020f,0.005f,0.003f}}
-/// {"glass",{0.06f,0.03f,0.02f,0.05f,0.060f,0.044f,0.011f}}
-/// {"plaster",{0.12f,0.06f,0.04f,0.05f,0.056f,0.056f,0.004f}}
-/// {"wood",{0.11f,0.07f,0.06f,0.05f,0.070f,0.014f,0.005f}}
-/// {"metal",{0.20f,0.07f,0.06f,0.05f,0.200f,0.025f,0.010f}}
-/// {"rock",{0.13f,0.20f,0.24f,0.05f,0.015f,0.002f,0.001f}}
+/// Acoustic material properties for a few standard materials are provided as
+/// associated constants, e.g. [`Material::BRICK`].
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(C)]
 pub struct Material {
     /// Fraction of sound energy absorbed at low, middle, high frequencies.
@@ -274,3 +637,124 @@ pub struct Material {
     /// calculations.
     pub transmission: [f32; 3],
 }
+
+impl Material {
+    /// A generic material, used when nothing more specific is known.
+    pub const GENERIC: Material = Material {
+        absorption: [0.1, 0.2, 0.3],
+        scattering: 0.05,
+        transmission: [0.1, 0.05, 0.03],
+    };
+
+    /// Acoustic material properties of brick.
+    pub const BRICK: Material = Material {
+        absorption: [0.03, 0.04, 0.07],
+        scattering: 0.05,
+        transmission: [0.015, 0.015, 0.015],
+    };
+
+    /// Acoustic material properties of concrete.
+    pub const CONCRETE: Material = Material {
+        absorption: [0.05, 0.07, 0.08],
+        scattering: 0.05,
+        transmission: [0.015, 0.002, 0.001],
+    };
+
+    /// Acoustic material properties of ceramic.
+    pub const CERAMIC: Material = Material {
+        absorption: [0.01, 0.02, 0.02],
+        scattering: 0.05,
+        transmission: [0.06, 0.044, 0.011],
+    };
+
+    /// Acoustic material properties of gravel.
+    pub const GRAVEL: Material = Material {
+        absorption: [0.6, 0.7, 0.8],
+        scattering: 0.05,
+        transmission: [0.031, 0.012, 0.008],
+    };
+
+    /// Acoustic material properties of carpet.
+    pub const CARPET: Material = Material {
+        absorption: [0.24, 0.69, 0.73],
+        scattering: 0.05,
+        transmission: [0.02, 0.005, 0.003],
+    };
+
+    /// Acoustic material properties of glass.
+    pub const GLASS: Material = Material {
+        absorption: [0.06, 0.03, 0.02],
+        scattering: 0.05,
+        transmission: [0.06, 0.044, 0.011],
+    };
+
+    /// Acoustic material properties of plaster.
+    pub const PLASTER: Material = Material {
+        absorption: [0.12, 0.06, 0.04],
+        scattering: 0.05,
+        transmission: [0.056, 0.056, 0.004],
+    };
+
+    /// Acoustic material properties of wood.
+    pub const WOOD: Material = Material {
+        absorption: [0.11, 0.07, 0.06],
+        scattering: 0.05,
+        transmission: [0.07, 0.014, 0.005],
+    };
+
+    /// Acoustic material properties of metal.
+    pub const METAL: Material = Material {
+        absorption: [0.2, 0.07, 0.06],
+        scattering: 0.05,
+        transmission: [0.2, 0.025, 0.01],
+    };
+
+    /// Acoustic material properties of rock.
+    pub const ROCK: Material = Material {
+        absorption: [0.13, 0.2, 0.24],
+        scattering: 0.05,
+        transmission: [0.015, 0.002, 0.001],
+    };
+
+    /// Linearly interpolates between two materials, `t` of 0 yields `a` and 1
+    /// yields `b`.
+    pub fn lerp(a: &Material, b: &Material, t: f32) -> Material {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Material {
+            absorption: std::array::from_fn(|i| lerp(a.absorption[i], b.absorption[i])),
+            scattering: lerp(a.scattering, b.scattering),
+            transmission: std::array::from_fn(|i| lerp(a.transmission[i], b.transmission[i])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_drop_meshes_within_update() {
+        let context = Context::new().unwrap();
+        let scene = context.create_scene().unwrap();
+        let add_mesh = |scene: &Scene| {
+            scene
+                .add_static_mesh(
+                    &[[0u32, 1, 2]],
+                    &[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                    &[0],
+                    &[Material::GENERIC],
+                )
+                .unwrap()
+        };
+
+        let handle = add_mesh(&scene);
+        let handle = scene.update(|scene| {
+            drop(handle);
+            add_mesh(scene)
+        });
+        assert!(!scene.is_dirty());
+
+        scene.update(|_| drop(handle));
+        assert!(!scene.is_dirty());
+    }
+}
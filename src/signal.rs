@@ -0,0 +1,109 @@
+use oddio::Signal;
+
+use crate::buffer::Buffer;
+
+#[inline]
+pub fn transform<I, F, const IN: usize, const OUT: usize>(
+    input: I,
+    function: F,
+    frame_size: u32,
+) -> Transform<I, F, IN, OUT>
+where
+    I: Signal<Frame = [f32; IN]>,
+    F: FnMut(&Buffer, &mut Buffer),
+{
+    let input_buffer = Buffer::new(IN as u16, frame_size);
+    let output_buffer = Buffer::new(OUT as u16, frame_size);
+
+    Transform {
+        input,
+        function,
+        input_frames: vec![[0.0; IN]; frame_size as usize],
+        input_buffer,
+        output_buffer,
+        position_in_frame: frame_size as usize,
+        frame_len: frame_size as usize,
+        finished: false,
+    }
+}
+
+/// Applies a function operating on fixed-size [`Buffer`]s to an `oddio`
+/// signal.
+///
+/// `oddio` may request any number of frames at once, while Steam Audio
+/// processes audio in blocks of `frame_size` samples, therefore the input is
+/// pulled one block at a time and the output is handed out from the last
+/// processed block.
+pub struct Transform<I, F, const IN: usize, const OUT: usize>
+where
+    I: Signal<Frame = [f32; IN]>,
+    F: FnMut(&Buffer, &mut Buffer),
+{
+    input: I,
+    function: F,
+
+    input_frames: Vec<[f32; IN]>,
+    input_buffer: Buffer,
+    output_buffer: Buffer,
+
+    position_in_frame: usize,
+    frame_len: usize,
+    finished: bool,
+}
+
+impl<I, F, const IN: usize, const OUT: usize> Transform<I, F, IN, OUT>
+where
+    I: Signal<Frame = [f32; IN]>,
+    F: FnMut(&Buffer, &mut Buffer),
+{
+    fn next_frame(&mut self, interval: f32) {
+        // Unlike rodio, oddio doesn't report how many frames are left, a finished
+        // input is padded with silence, therefore stop at the block boundary.
+        if self.input.is_finished() {
+            self.finished = true;
+            self.frame_len = 0;
+            self.position_in_frame = 0;
+            return;
+        }
+
+        self.input.sample(interval, &mut self.input_frames);
+        for (frame, values) in self.input_frames.iter().enumerate() {
+            for (channel, value) in values.iter().enumerate() {
+                self.input_buffer.data()[channel][frame] = *value;
+            }
+        }
+        (self.function)(&self.input_buffer, &mut self.output_buffer);
+
+        self.frame_len = self.input_frames.len();
+        self.position_in_frame = 0;
+    }
+}
+
+impl<I, F, const IN: usize, const OUT: usize> Signal for Transform<I, F, IN, OUT>
+where
+    I: Signal<Frame = [f32; IN]>,
+    F: FnMut(&Buffer, &mut Buffer),
+{
+    type Frame = [f32; OUT];
+
+    fn sample(&mut self, interval: f32, out: &mut [Self::Frame]) {
+        for frame in out {
+            if self.position_in_frame >= self.frame_len && !self.finished {
+                self.next_frame(interval);
+            }
+
+            if self.position_in_frame < self.frame_len {
+                for (channel, value) in frame.iter_mut().enumerate() {
+                    *value = self.output_buffer.data[channel][self.position_in_frame];
+                }
+                self.position_in_frame += 1;
+            } else {
+                *frame = [0.0; OUT];
+            }
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished && self.position_in_frame >= self.frame_len
+    }
+}
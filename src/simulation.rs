@@ -1,36 +1,74 @@
-use std::cell::RefCell;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex, RwLockReadGuard,
+    },
+    time::Instant,
+};
 
-use glam::Vec3;
+use glam::{Quat, Vec3};
+use tracing::{debug, warn};
 
 use crate::{
     context::Context,
-    error::{check, Result},
+    effect::{ReflectionEffectType, Reset},
+    error::{check, Error, Result},
     ffi,
     geometry::Orientation,
-    scene::Scene,
+    probe::{BakedDataIdentifier, ProbeBatch},
+    scene::{Scene, SceneType},
 };
 
 impl Context {
+    /// Creates a simulator with the default settings, see
+    /// [`SimulationSettings::new`].
     pub fn create_simulator(&self, sampling_rate: u32, frame_size: u32) -> Result<Simulator> {
-        let mut simulation_settings = ffi::IPLSimulationSettings {
-            flags: ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-            sceneType: ffi::IPLSceneType_IPL_SCENETYPE_DEFAULT,
-            reflectionType: 0,
-            maxNumOcclusionSamples: 0,
-            maxNumRays: 0,
-            numDiffuseSamples: 0,
-            maxDuration: 0.0,
-            maxOrder: 0,
-            maxNumSources: 0,
-            numThreads: 0,
-            rayBatchSize: 0,
-            numVisSamples: 0,
-            samplingRate: sampling_rate as i32,
-            frameSize: frame_size as i32,
-            openCLDevice: std::ptr::null_mut(),
-            radeonRaysDevice: std::ptr::null_mut(),
-            tanDevice: std::ptr::null_mut(),
-        };
+        self.create_simulator_with_settings(SimulationSettings::new(sampling_rate, frame_size))
+    }
+
+    /// Creates a simulator for the given scene which simulates reflections,
+    /// see [`SimulationSettings::with_reflections`].
+    ///
+    /// The reflection parameters are set to the maximums of the settings with
+    /// 16 bounces, and the scene is set and committed, so that reflections
+    /// can be simulated for sources with [`Source::set_reflections`] right
+    /// away.
+    ///
+    /// Returns [`Error::ExceedsMaximum`] if the settings don't allow
+    /// simulating reflections, i.e. the maximum number of rays is 0.
+    pub fn create_reflections_simulator(
+        &self,
+        settings: SimulationSettings,
+        scene: &Scene,
+    ) -> Result<Simulator> {
+        // Reflections need at least one ray.
+        if settings.maximum_rays == 0 {
+            return Err(Error::ExceedsMaximum {
+                parameter: "number of rays",
+                value: 1.0,
+                maximum: 0.0,
+            });
+        }
+
+        let (rays, duration, order) = (
+            settings.maximum_rays,
+            settings.maximum_duration,
+            settings.maximum_order,
+        );
+        let mut simulator = self.create_simulator_with_settings(settings)?;
+        simulator.set_scene(scene);
+        simulator.set_reflections(rays, 16, duration, order, 1.0)?;
+        simulator.commit();
+
+        Ok(simulator)
+    }
+
+    /// Creates a simulator.
+    pub fn create_simulator_with_settings(
+        &self,
+        settings: SimulationSettings,
+    ) -> Result<Simulator> {
+        let mut simulation_settings = ffi::IPLSimulationSettings::from(&settings);
         let mut simulator = std::ptr::null_mut();
 
         unsafe {
@@ -38,11 +76,163 @@ impl Context {
                 ffi::iplSimulatorCreate(self.inner, &mut simulation_settings, &mut simulator),
                 Simulator {
                     inner: simulator,
-                    shared_inputs: RefCell::new(std::mem::zeroed()),
+                    state: Default::default(),
+                    direct_dirty: Arc::new(AtomicBool::new(true)),
+                    commit_pending: Default::default(),
+                    sampling_rate: settings.sampling_rate,
+                    frame_size: settings.frame_size,
+                    maximum_order: settings.maximum_order,
+                    maximum_duration: settings.maximum_duration,
+                    maximum_occlusion_samples: settings.maximum_occlusion_samples,
+                    reflection_effect_type: settings.reflection_effect_type,
                 },
             )
         }
     }
+
+    /// Creates `count` simulators with the same settings, e.g. one per listener
+    /// for split-screen or spectator views, see [`Simulator`].
+    pub fn create_simulators(
+        &self,
+        settings: SimulationSettings,
+        count: usize,
+    ) -> Result<Vec<Simulator>> {
+        (0..count)
+            .map(|_| self.create_simulator_with_settings(settings.clone()))
+            .collect()
+    }
+}
+
+/// Settings used to create a simulator.
+///
+/// [`SimulationSettings::new`] only enables direct simulation, individual
+/// defaults, e.g. the number of rays or diffuse samples, are overridden using
+/// struct update syntax.
+#[derive(Clone)]
+pub struct SimulationSettings {
+    /// The sampling rate (in Hz) used for audio processing.
+    pub sampling_rate: u32,
+
+    /// The size (in number of samples) of the audio buffers used for audio
+    /// processing.
+    pub frame_size: u32,
+
+    /// The maximum number of point samples to consider when calculating
+    /// volumetric occlusion.
+    pub maximum_occlusion_samples: u32,
+
+    /// The maximum number of rays to trace from the listener when simulating
+    /// reflections, reflections are only simulated if this is greater than 0.
+    pub maximum_rays: u32,
+
+    /// The number of directions to sample when generating diffusely reflected
+    /// rays.
+    pub diffuse_samples: u32,
+
+    /// The maximum length (in seconds) of impulse responses generated by
+    /// reflection simulations.
+    pub maximum_duration: f32,
+
+    /// The maximum Ambisonic order of impulse responses generated by
+    /// reflection simulations.
+    pub maximum_order: u8,
+
+    /// The maximum number of sources for which reflection simulations will be
+    /// run at any given time.
+    pub maximum_sources: u32,
+
+    /// The number of threads used for real-time reflection simulations.
+    pub threads: u32,
+
+    /// The number of rays traced in a single batch, only used with custom ray
+    /// tracers.
+    pub ray_batch_size: u32,
+
+    /// The type of reflection effect the simulation results are rendered
+    /// with.
+    pub reflection_effect_type: ReflectionEffectType,
+
+    /// The ray tracer used for simulation, must match the type of the scenes
+    /// set on the simulator.
+    pub scene_type: SceneType,
+}
+
+impl SimulationSettings {
+    /// Creates settings for a simulator which only simulates direct sound.
+    pub fn new(sampling_rate: u32, frame_size: u32) -> Self {
+        Self {
+            sampling_rate,
+            frame_size,
+            maximum_occlusion_samples: 16,
+            maximum_rays: 0,
+            diffuse_samples: 0,
+            maximum_duration: 0.0,
+            maximum_order: 0,
+            maximum_sources: 0,
+            threads: 0,
+            ray_batch_size: 0,
+            reflection_effect_type: ReflectionEffectType::Convolution,
+            scene_type: SceneType::Default,
+        }
+    }
+
+    /// Creates settings for a simulator which simulates direct sound and
+    /// reflections, with defaults suitable for real-time simulation of a few
+    /// sources, up to first order Ambisonics and impulse responses of 1 second.
+    pub fn with_reflections(sampling_rate: u32, frame_size: u32) -> Self {
+        Self {
+            maximum_rays: 4096,
+            diffuse_samples: 32,
+            maximum_duration: 1.0,
+            maximum_order: 1,
+            maximum_sources: 8,
+            threads: 2,
+            ..Self::new(sampling_rate, frame_size)
+        }
+    }
+}
+
+impl From<&SimulationSettings> for ffi::IPLSimulationSettings {
+    fn from(settings: &SimulationSettings) -> Self {
+        let mut flags = ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        if settings.maximum_rays > 0 {
+            flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS;
+        }
+        let (mut open_cl_device, tan_device) = match &settings.reflection_effect_type {
+            ReflectionEffectType::Convolution | ReflectionEffectType::Parametric => {
+                (std::ptr::null_mut(), std::ptr::null_mut())
+            }
+            ReflectionEffectType::TrueAudioNext { device, .. } => {
+                (device.open_cl_device.inner, device.inner)
+            }
+        };
+        let radeon_rays_device = match &settings.scene_type {
+            SceneType::Default => std::ptr::null_mut(),
+            SceneType::RadeonRays(device) => {
+                open_cl_device = device.open_cl_device.inner;
+                device.inner
+            }
+        };
+        ffi::IPLSimulationSettings {
+            flags,
+            sceneType: (&settings.scene_type).into(),
+            reflectionType: (&settings.reflection_effect_type).into(),
+            maxNumOcclusionSamples: settings.maximum_occlusion_samples as i32,
+            maxNumRays: settings.maximum_rays as i32,
+            numDiffuseSamples: settings.diffuse_samples as i32,
+            maxDuration: settings.maximum_duration,
+            maxOrder: settings.maximum_order as i32,
+            maxNumSources: settings.maximum_sources as i32,
+            numThreads: settings.threads as i32,
+            rayBatchSize: settings.ray_batch_size as i32,
+            numVisSamples: 0,
+            samplingRate: settings.sampling_rate as i32,
+            frameSize: settings.frame_size as i32,
+            openCLDevice: open_cl_device,
+            radeonRaysDevice: radeon_rays_device,
+            tanDevice: tan_device,
+        }
+    }
 }
 
 /// Manages direct and indirect sound propagation simulation for multiple
@@ -50,9 +240,29 @@ impl Context {
 /// it to run simulations with different source and listener parameters between
 /// consecutive simulation runs. The simulator can also be reused across scene
 /// changes.
+///
+/// A simulator simulates a single listener. Multiple listeners, e.g. for
+/// split-screen, require one simulator per listener, which can share the same
+/// [`Scene`], while sources have to be created for each simulator, see
+/// [`Context::create_simulators`].
 pub struct Simulator {
     inner: ffi::IPLSimulator,
-    shared_inputs: RefCell<ffi::IPLSimulationSharedInputs>,
+    /// State shared between all handles of the same simulator.
+    state: Arc<Mutex<SimulatorState>>,
+
+    /// Whether any inputs changed since the last direct simulation, shared
+    /// between all handles of the same simulator.
+    direct_dirty: Arc<AtomicBool>,
+
+    /// Whether a commit was deferred to the next simulation run.
+    commit_pending: Arc<AtomicBool>,
+
+    sampling_rate: u32,
+    frame_size: u32,
+    maximum_order: u8,
+    pub(crate) maximum_duration: f32,
+    maximum_occlusion_samples: u32,
+    pub(crate) reflection_effect_type: ReflectionEffectType,
 }
 
 impl Simulator {
@@ -62,31 +272,120 @@ impl Simulator {
         unsafe {
             ffi::iplSimulatorSetScene(self.inner, scene.inner);
         }
+
+        self.state.lock().unwrap().scene = Some(scene.clone());
+        self.mark_direct_dirty();
+    }
+
+    /// Switches to another scene, e.g. on a level change, without recreating
+    /// the simulator and its sources.
+    ///
+    /// The new scene is committed, and the given effects are reset, so that no
+    /// reverb tails or filter state of the previous scene remain audible.
+    /// Reflections and pathing have to be simulated again before the effects
+    /// are applied, as the sources still hold the results of the previous
+    /// scene until then.
+    pub fn reset(&mut self, scene: &Scene, effects: &[&dyn Reset]) {
+        self.set_scene(scene);
+        self.commit();
+
+        for effect in effects {
+            effect.reset();
+        }
+    }
+
+    /// Returns the sampling rate (in Hz) the simulator was created with, which
+    /// effects rendering its sources must be created with as well.
+    pub fn sampling_rate(&self) -> u32 {
+        self.sampling_rate
+    }
+
+    /// Returns the frame size (in number of samples) the simulator was created
+    /// with, which effects rendering its sources must be created with as well.
+    pub fn frame_size(&self) -> u32 {
+        self.frame_size
+    }
+
+    /// Adds a probe batch for use in subsequent simulations. Sources that
+    /// require baked data can then use the data contained in the batch.
+    ///
+    /// The simulator must be committed afterwards for the change to take
+    /// effect.
+    pub fn add_probe_batch(&mut self, probe_batch: &ProbeBatch) {
+        unsafe {
+            ffi::iplSimulatorAddProbeBatch(self.inner, probe_batch.inner);
+        }
+    }
+
+    /// Removes a probe batch from use in subsequent simulations.
+    ///
+    /// The simulator must be committed afterwards for the change to take
+    /// effect.
+    pub fn remove_probe_batch(&mut self, probe_batch: &ProbeBatch) {
+        unsafe {
+            ffi::iplSimulatorRemoveProbeBatch(self.inner, probe_batch.inner);
+        }
     }
 
     /// Commits changes to the scene or probe batches used for simulation.
+    ///
+    /// Committing is expensive, as it applies to all sources of the simulator,
+    /// changes should therefore be batched and committed once.
     pub fn commit(&self) {
+        self.commit_pending.store(false, Ordering::Relaxed);
         unsafe {
             ffi::iplSimulatorCommit(self.inner);
         }
+
+        self.mark_direct_dirty();
+    }
+
+    /// Defers committing to the start of the next simulation run, so that
+    /// adding and removing many sources, e.g. when spawning projectiles,
+    /// results in a single commit per frame instead of one per change.
+    pub fn commit_deferred(&self) {
+        self.commit_pending.store(true, Ordering::Relaxed);
+        self.mark_direct_dirty();
     }
 
     /// Specifies simulation parameters that are not associated with any
     /// particular source.
     pub fn set_listener(&mut self, listener: Orientation) {
-        self.shared_inputs.get_mut().listener = listener.into();
+        {
+            let mut state = self.state.lock().unwrap();
+            state.shared_inputs.listener = listener.into();
 
-        unsafe {
-            ffi::iplSimulatorSetSharedInputs(
-                self.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT
-                    | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS
-                    | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_PATHING,
-                self.shared_inputs.as_ptr(),
-            );
+            unsafe {
+                ffi::iplSimulatorSetSharedInputs(
+                    self.inner,
+                    ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT
+                        | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS
+                        | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_PATHING,
+                    &mut state.shared_inputs,
+                );
+            }
         }
+        self.update_reverb_source();
+        self.mark_direct_dirty();
     }
 
+    /// Specifies the position and rotation of the listener, see
+    /// [`Simulator::set_listener`].
+    pub fn set_listener_pose(&mut self, translation: Vec3, rotation: Quat) {
+        self.set_listener(Orientation {
+            translation,
+            rotation,
+        });
+    }
+
+    /// Specifies the reflection simulation parameters shared by all sources.
+    ///
+    /// The number of threads and the ray batch size can't be changed at
+    /// runtime, and are specified when creating the simulator, see
+    /// [`SimulationSettings`].
+    ///
+    /// Returns [`Error::ExceedsMaximum`] if `order` or `duration` exceed the
+    /// maximum order or duration the simulator was created with.
     pub fn set_reflections(
         &mut self,
         rays: u32,
@@ -94,8 +393,24 @@ impl Simulator {
         duration: f32,
         order: u8,
         irradiance_minimum_distance: f32,
-    ) {
-        let shared_inputs = self.shared_inputs.get_mut();
+    ) -> Result<()> {
+        if order > self.maximum_order {
+            return Err(Error::ExceedsMaximum {
+                parameter: "order",
+                value: order as f32,
+                maximum: self.maximum_order as f32,
+            });
+        }
+        if duration > self.maximum_duration {
+            return Err(Error::ExceedsMaximum {
+                parameter: "duration",
+                value: duration,
+                maximum: self.maximum_duration,
+            });
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let shared_inputs = &mut state.shared_inputs;
         shared_inputs.numRays = rays as i32;
         shared_inputs.numBounces = bounces as i32;
         shared_inputs.duration = duration;
@@ -106,9 +421,11 @@ impl Simulator {
             ffi::iplSimulatorSetSharedInputs(
                 self.inner,
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS,
-                self.shared_inputs.as_ptr(),
+                shared_inputs,
             );
         }
+
+        Ok(())
     }
 
     /// Runs a direct simulation for all sources added to the simulator. This
@@ -117,21 +434,74 @@ impl Simulator {
     ///
     /// This function should not be called from the audio processing thread if
     /// occlusion and/or transmission are enabled.
+    ///
+    /// Steam Audio always simulates all active sources, there is no way to
+    /// simulate a subset. Sources which rarely need updates, e.g. static
+    /// sources with occlusion, can be kept on a separate simulator sharing the
+    /// same scene, which is only run when they change, see
+    /// [`Simulator::needs_direct_run`].
     pub fn run_direct(&self) {
+        let scene = self.scene();
+        let _guard = self.lock_scene(scene.as_ref());
+        self.direct_dirty.store(false, Ordering::Relaxed);
+
         unsafe {
             ffi::iplSimulatorRunDirect(self.inner);
         }
     }
 
+    /// Returns `true` if the listener, any source or the scene changed since
+    /// the last direct simulation, so that [`Simulator::run_direct`] can be
+    /// skipped if nothing moved.
+    pub fn needs_direct_run(&self) -> bool {
+        self.direct_dirty.load(Ordering::Relaxed)
+            || self.scene().as_ref().is_some_and(Scene::is_dirty)
+    }
+
+    fn mark_direct_dirty(&self) {
+        self.direct_dirty.store(true, Ordering::Relaxed);
+    }
+
     /// Runs a reflections simulation for all sources added to the simulator.
     ///
     /// This function can be CPU intensive, and should be called from a separate
     /// thread in order to not block either the audio processing thread or
     /// the game's main update thread.
+    ///
+    /// Ray directions are randomized and Steam Audio doesn't expose a seed, the
+    /// results therefore vary slightly between runs. Comparisons, e.g. in
+    /// regression tests, should average the results of several runs, see
+    /// [`Source::reverb_times`].
+    ///
+    /// Each run is logged at debug level with the number of rays cast from the
+    /// listener, the number of bounces and the time taken, and a warning is
+    /// logged if no scene is set, in which case no reflections are simulated.
     pub fn run_reflections(&self) {
+        let (scene, rays, bounces) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.scene.clone(),
+                state.shared_inputs.numRays,
+                state.shared_inputs.numBounces,
+            )
+        };
+        if scene.is_none() {
+            warn!("running reflections simulation without a scene");
+        }
+
+        let _guard = self.lock_scene(scene.as_ref());
+
+        let start = Instant::now();
         unsafe {
             ffi::iplSimulatorRunReflections(self.inner);
         }
+
+        debug!(
+            rays_cast = rays,
+            bounces,
+            elapsed = ?start.elapsed(),
+            "ran reflections simulation"
+        );
     }
 
     /// Runs a pathing simulation for all sources added to the simulator.
@@ -140,11 +510,130 @@ impl Simulator {
     /// thread in order to not block either the audio processing thread or
     /// the game's main update thread.
     pub fn run_pathing(&self) {
+        let scene = self.scene();
+        let _guard = self.lock_scene(scene.as_ref());
+
         unsafe {
             ffi::iplSimulatorRunPathing(self.inner);
         }
     }
 
+    /// The scene last set on any handle of this simulator.
+    fn scene(&self) -> Option<Scene> {
+        self.state.lock().unwrap().scene.clone()
+    }
+
+    /// Commits pending changes and locks the scene for the duration of a
+    /// simulation run.
+    ///
+    /// The scene is committed before it is locked, as committing requires
+    /// exclusive access, see [`Scene::update`].
+    fn lock_scene<'a>(&self, scene: Option<&'a Scene>) -> Option<RwLockReadGuard<'a, ()>> {
+        let mut commit = self.commit_pending.load(Ordering::Relaxed);
+        if let Some(scene) = scene {
+            commit |= scene.auto_commit();
+        }
+
+        let guard = scene.map(|scene| {
+            let guard = scene.lock.read().unwrap();
+            debug_assert!(
+                !scene.is_dirty(),
+                "scene meshes changed without committing the scene"
+            );
+            guard
+        });
+        if commit {
+            self.commit();
+        }
+
+        guard
+    }
+
+    /// Runs a reflections simulation for all sources added to the simulator on
+    /// the blocking thread pool of the tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn run_reflections_async(&self) {
+        let simulator = self.clone();
+        tokio::task::spawn_blocking(move || simulator.run_reflections())
+            .await
+            .unwrap()
+    }
+
+    /// Runs a pathing simulation for all sources added to the simulator on the
+    /// blocking thread pool of the tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn run_pathing_async(&self) {
+        let simulator = self.clone();
+        tokio::task::spawn_blocking(move || simulator.run_pathing())
+            .await
+            .unwrap()
+    }
+
+    /// Creates a listener-centric reverb source.
+    ///
+    /// The reverb source is always positioned at the listener, and is used to
+    /// simulate reverb for the listener's surroundings. The returned source
+    /// can be passed to a reflection effect to apply global reverb, without
+    /// having to position a source at the listener manually. Its position is
+    /// managed by the simulator, which moves it along with the listener, and
+    /// should not be changed.
+    ///
+    /// A simulator has at most one reverb source, creating another one replaces
+    /// the previous one, which is no longer simulated.
+    ///
+    /// The reverb source is added to the simulator, which must be committed
+    /// for it to be simulated.
+    pub fn create_reverb_source(&mut self) -> Result<Source> {
+        let source = self.create_source_active(true)?;
+        {
+            let inputs = &mut source.state.lock().unwrap().inputs;
+            inputs.flags = ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS;
+            inputs.reverbScale = [1.0, 1.0, 1.0];
+        }
+
+        {
+            let mut state = self.state.lock().unwrap();
+            unsafe {
+                if !state.reverb_source.is_null() {
+                    ffi::iplSourceRemove(state.reverb_source, self.inner);
+                    ffi::iplSourceRelease(&mut state.reverb_source);
+                }
+                state.reverb_source = ffi::iplSourceRetain(source.inner);
+            }
+            state.reverb_source_state = Some(source.state.clone());
+        }
+        self.update_reverb_source();
+
+        Ok(source)
+    }
+
+    fn update_reverb_source(&self) {
+        let (reverb_source, reverb_source_state, listener) = {
+            let state = self.state.lock().unwrap();
+            let Some(reverb_source_state) = state.reverb_source_state.clone() else {
+                return;
+            };
+            (
+                state.reverb_source,
+                reverb_source_state,
+                state.shared_inputs.listener,
+            )
+        };
+
+        // The inputs are kept in the state of the returned source, as any of its
+        // setters submits them again.
+        let inputs = &mut reverb_source_state.lock().unwrap().inputs;
+        inputs.source = listener;
+
+        unsafe {
+            ffi::iplSourceSetInputs(
+                reverb_source,
+                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS,
+                inputs,
+            );
+        }
+    }
+
     /// Creates a simulation source.
     pub fn create_source(&self) -> Result<Source> {
         let mut source_settings = ffi::IPLSourceSettings { flags: 0 };
@@ -155,12 +644,43 @@ impl Simulator {
                 ffi::iplSourceCreate(self.inner, &mut source_settings, &mut source),
                 Source {
                     inner: source,
-                    inputs: RefCell::new(std::mem::zeroed()),
+                    state: Arc::new(Mutex::new(SourceState {
+                        inputs: std::mem::zeroed(),
+                        transmission_type: TransmissionType::FrequencyIndependent,
+                    })),
+                    gain: Arc::new(AtomicU32::new(1.0f32.to_bits())),
                     simulator: self.clone(),
                 },
             )
         }
     }
+
+    /// Creates a simulation source, and adds it to the set of sources
+    /// processed by the simulator if `active` is `true`, see
+    /// [`Source::set_active`].
+    ///
+    /// The simulator must be committed for an active source to be simulated.
+    pub fn create_source_active(&self, active: bool) -> Result<Source> {
+        let mut source = self.create_source()?;
+        if active {
+            source.set_active(true);
+        }
+
+        Ok(source)
+    }
+
+    /// Creates `count` simulation sources, adds them to the simulator and
+    /// commits once, which is considerably faster than creating and
+    /// committing each source individually.
+    pub fn create_sources(&self, count: usize) -> Result<Vec<Source>> {
+        let mut sources = Vec::with_capacity(count);
+        for _ in 0..count {
+            sources.push(self.create_source_active(true)?);
+        }
+        self.commit();
+
+        Ok(sources)
+    }
 }
 
 impl Clone for Simulator {
@@ -171,11 +691,29 @@ impl Clone for Simulator {
 
         Self {
             inner: self.inner,
-            shared_inputs: self.shared_inputs.clone(),
+            state: self.state.clone(),
+            direct_dirty: self.direct_dirty.clone(),
+            commit_pending: self.commit_pending.clone(),
+            sampling_rate: self.sampling_rate,
+            frame_size: self.frame_size,
+            maximum_order: self.maximum_order,
+            maximum_duration: self.maximum_duration,
+            maximum_occlusion_samples: self.maximum_occlusion_samples,
+            reflection_effect_type: self.reflection_effect_type.clone(),
         }
     }
 }
 
+impl std::fmt::Debug for Simulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Simulator")
+            .field("inner", &self.inner)
+            .field("sampling_rate", &self.sampling_rate)
+            .field("frame_size", &self.frame_size)
+            .finish()
+    }
+}
+
 impl Drop for Simulator {
     fn drop(&mut self) {
         unsafe {
@@ -188,19 +726,55 @@ unsafe impl Send for Simulator {}
 
 unsafe impl Sync for Simulator {}
 
+struct SimulatorState {
+    shared_inputs: ffi::IPLSimulationSharedInputs,
+    scene: Option<Scene>,
+    reverb_source: ffi::IPLSource,
+    reverb_source_state: Option<Arc<Mutex<SourceState>>>,
+}
+
+impl Default for SimulatorState {
+    fn default() -> Self {
+        Self {
+            shared_inputs: unsafe { std::mem::zeroed() },
+            scene: None,
+            reverb_source: std::ptr::null_mut(),
+            reverb_source_state: None,
+        }
+    }
+}
+
+impl Drop for SimulatorState {
+    fn drop(&mut self) {
+        if !self.reverb_source.is_null() {
+            unsafe {
+                ffi::iplSourceRelease(&mut self.reverb_source);
+            }
+        }
+    }
+}
+
+unsafe impl Send for SimulatorState {}
+
 /// A sound source, for the purposes of simulation. This object is used to
 /// specify various parameters for direct and indirect sound propagation
 /// simulation, and to retrieve the simulation results.
 pub struct Source {
     pub(crate) inner: ffi::IPLSource,
-    pub(crate) inputs: RefCell<ffi::IPLSimulationInputs>,
+    /// Shared by all clones, so that effects applied on the audio thread see
+    /// the inputs configured on another thread.
+    pub(crate) state: Arc<Mutex<SourceState>>,
+    pub(crate) gain: Arc<AtomicU32>,
 
-    simulator: Simulator,
+    pub(crate) simulator: Simulator,
 }
 
 impl Source {
     /// Adds or removes a source to the set of sources processed by a simulator
     /// in subsequent simulations.
+    ///
+    /// The simulator must be committed afterwards for the change to take
+    /// effect, see [`Simulator::commit_deferred`] for batching changes.
     pub fn set_active(&mut self, active: bool) {
         unsafe {
             if active {
@@ -209,11 +783,21 @@ impl Source {
                 ffi::iplSourceRemove(self.inner, self.simulator.inner)
             }
         }
+        self.simulator.mark_direct_dirty();
     }
 
-    /// The position and orientation of this source.
-    pub fn set_source(&mut self, source: Orientation) {
-        self.inputs.get_mut().source = source.into();
+    /// Applies several changes to the simulation inputs of this source at
+    /// once, submitting them to the simulator with a single call.
+    pub fn configure(&mut self, function: impl FnOnce(&mut SourceInputs)) {
+        let mut state = self.state.lock().unwrap();
+        let SourceState {
+            inputs,
+            transmission_type,
+        } = &mut *state;
+        function(&mut SourceInputs {
+            inner: inputs,
+            transmission_type,
+        });
 
         unsafe {
             ffi::iplSourceSetInputs(
@@ -221,9 +805,22 @@ impl Source {
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT
                     | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS
                     | ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_PATHING,
-                self.inputs.as_ptr(),
+                inputs,
             );
         }
+        drop(state);
+        self.simulator.mark_direct_dirty();
+    }
+
+    /// The position and orientation of this source.
+    pub fn set_source(&mut self, source: Orientation) {
+        self.configure(|inputs| inputs.set_source(source));
+    }
+
+    /// The orientation of this source, used for directivity, keeping its
+    /// position.
+    pub fn set_directivity_orientation(&mut self, rotation: Quat) {
+        self.configure(|inputs| inputs.set_directivity_orientation(rotation));
     }
 
     /// Apply frequency-independent distance attenuation.
@@ -231,97 +828,168 @@ impl Source {
         &mut self,
         distance_attenuation_model: DistanceAttenuationModel,
     ) {
-        let inputs = self.inputs.get_mut();
-        inputs.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
-        inputs.directFlags |=
-            ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DISTANCEATTENUATION;
-        inputs.distanceAttenuationModel = distance_attenuation_model.into();
-
-        unsafe {
-            ffi::iplSourceSetInputs(
-                self.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
-            );
-        }
+        self.configure(|inputs| inputs.set_distance_attenuation(distance_attenuation_model));
     }
 
     /// Apply frequency-dependent air absorption as a function of distance.
     pub fn set_air_absorption(&mut self, air_absorption_model: AirAbsorptionModel) {
-        let inputs = self.inputs.get_mut();
-        inputs.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
-        inputs.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_AIRABSORPTION;
-        inputs.airAbsorptionModel = air_absorption_model.into();
-
-        unsafe {
-            ffi::iplSourceSetInputs(
-                self.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
-            );
-        }
+        self.configure(|inputs| inputs.set_air_absorption(air_absorption_model));
     }
 
     /// Apply attenuation due to source directivity pattern.
     pub fn set_directivity(&mut self, directivity: Directivity) {
-        let inputs = self.inputs.get_mut();
-        inputs.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
-        inputs.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DIRECTIVITY;
-        inputs.directivity = directivity.into();
-
-        unsafe {
-            ffi::iplSourceSetInputs(
-                self.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
-            );
-        }
+        self.configure(|inputs| inputs.set_directivity(directivity));
     }
 
     /// Apply occlusion.
     pub fn set_occlusion(&mut self) {
-        let inputs = self.inputs.get_mut();
-        inputs.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
-        inputs.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_OCCLUSION;
-        inputs.occlusionType = ffi::IPLOcclusionType_IPL_OCCLUSIONTYPE_RAYCAST;
+        self.configure(|inputs| inputs.set_occlusion());
+    }
 
+    /// Apply volumetric occlusion, the source is modeled as a sphere with the
+    /// given radius, and `samples` points within the sphere are tested for
+    /// visibility from the listener.
+    ///
+    /// Returns [`Error::ExceedsMaximum`] if `samples` exceeds the maximum
+    /// number of occlusion samples the simulator was created with.
+    pub fn set_volumetric_occlusion(&mut self, radius: f32, samples: u32) -> Result<()> {
+        if samples > self.simulator.maximum_occlusion_samples {
+            return Err(Error::ExceedsMaximum {
+                parameter: "number of occlusion samples",
+                value: samples as f32,
+                maximum: self.simulator.maximum_occlusion_samples as f32,
+            });
+        }
+
+        self.configure(|inputs| inputs.set_volumetric_occlusion(radius, samples));
+        Ok(())
+    }
+
+    /// Apply transmission along with occlusion.
+    ///
+    /// `maximum_surfaces` is the maximum number of surfaces, starting from the
+    /// closest surface to the listener, whose transmission coefficients are
+    /// taken into account when calculating the total amount of sound
+    /// transmitted. Must be higher than 0, higher numbers give more accurate
+    /// results when multiple surfaces lie between the source and the listener,
+    /// at the cost of increased CPU usage.
+    ///
+    /// `transmission_type` selects whether the direct effect applies the
+    /// transmission coefficients in a single band or per frequency band.
+    pub fn set_transmission(&mut self, maximum_surfaces: u32, transmission_type: TransmissionType) {
+        self.configure(|inputs| inputs.set_transmission(maximum_surfaces, transmission_type));
+    }
+
+    pub fn set_reflections(&mut self) {
+        self.configure(|inputs| inputs.set_reflections());
+    }
+
+    /// Use baked data identified by `identifier` instead of simulating in real
+    /// time, see [`Simulator::add_probe_batch`].
+    pub fn set_baked_data_identifier(&mut self, identifier: BakedDataIdentifier) {
+        self.configure(|inputs| inputs.set_baked_data_identifier(identifier));
+    }
+
+    /// Stop applying distance attenuation, e.g. when the source switches to
+    /// non-spatial playback.
+    pub fn clear_distance_attenuation(&mut self) {
+        self.configure(|inputs| inputs.clear_distance_attenuation());
+    }
+
+    /// Stop applying air absorption.
+    pub fn clear_air_absorption(&mut self) {
+        self.configure(|inputs| inputs.clear_air_absorption());
+    }
+
+    /// Stop applying attenuation due to source directivity.
+    pub fn clear_directivity(&mut self) {
+        self.configure(|inputs| inputs.clear_directivity());
+    }
+
+    /// Stop applying occlusion and transmission.
+    pub fn clear_occlusion(&mut self) {
+        self.configure(|inputs| inputs.clear_occlusion());
+    }
+
+    /// Stop applying transmission, keeping occlusion.
+    pub fn clear_transmission(&mut self) {
+        self.configure(|inputs| inputs.clear_transmission());
+    }
+
+    pub fn clear_reflections(&mut self) {
+        self.configure(|inputs| inputs.clear_reflections());
+    }
+
+    /// Linear gain applied by the direct effect on top of the simulated
+    /// attenuation, e.g. to duck or emphasize this source. Defaults to 1.0.
+    ///
+    /// The gain is shared by all clones of the source, so it can be changed
+    /// while a clone is used by the direct effect on the audio thread.
+    pub fn set_gain(&self, gain: f32) {
+        self.gain.store(gain.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns the direction from the listener to this source, expressed in
+    /// the listener's coordinate system, for use as the direction of the
+    /// effects rendering this source, see
+    /// [`Context::calculate_relative_direction`].
+    ///
+    /// Unlike computing the direction separately, this uses the position last
+    /// submitted to the simulator, so the simulated and rendered directions
+    /// can't disagree.
+    pub fn relative_direction(&self, context: &Context, listener: Orientation) -> Vec3 {
+        context.calculate_relative_direction(
+            self.state.lock().unwrap().inputs.source.origin.into(),
+            listener,
+        )
+    }
+
+    /// Returns the fraction of sound energy that is not occluded, between 0.0
+    /// (fully occluded) and 1.0 (not occluded), as computed by the last direct
+    /// simulation.
+    pub fn occlusion(&self) -> f32 {
         unsafe {
-            ffi::iplSourceSetInputs(
+            let mut simulation_outputs: ffi::IPLSimulationOutputs = std::mem::zeroed();
+
+            ffi::iplSourceGetOutputs(
                 self.inner,
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
+                &mut simulation_outputs,
             );
+            simulation_outputs.direct.occlusion
         }
     }
 
-    /// Apply transmission along with occlusion.
-    /// `max_num_surfaces` is the max amount of surfaces that will be taken into
-    /// account for the transmission effect. Must be higher than 0. Higher
-    /// numbers increase CPU usage.
-    pub fn set_transmission(&mut self, max_num_surfaces: i32) {
-        let inputs = self.inputs.get_mut();
-        inputs.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
-        inputs.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_TRANSMISSION;
-        inputs.numTransmissionRays = max_num_surfaces;
-
+    /// Returns the fraction of sound energy transmitted through occluding
+    /// geometry at low, middle, high frequencies, as computed by the last
+    /// direct simulation.
+    pub fn transmission(&self) -> [f32; 3] {
         unsafe {
-            ffi::iplSourceSetInputs(
+            let mut simulation_outputs: ffi::IPLSimulationOutputs = std::mem::zeroed();
+
+            ffi::iplSourceGetOutputs(
                 self.inner,
                 ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
+                &mut simulation_outputs,
             );
+            simulation_outputs.direct.transmission
         }
     }
 
-    pub fn set_reflections(&mut self) {
-        self.inputs.get_mut().flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS;
-
+    /// Returns the time (in seconds) for the reverb to decay by 60 dB at low,
+    /// middle, high frequencies, as computed by the last reflections
+    /// simulation. Only computed if the simulator was created with a
+    /// parametric reflection effect type.
+    pub fn reverb_times(&self) -> [f32; 3] {
         unsafe {
-            ffi::iplSourceSetInputs(
+            let mut simulation_outputs: ffi::IPLSimulationOutputs = std::mem::zeroed();
+
+            ffi::iplSourceGetOutputs(
                 self.inner,
-                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT,
-                self.inputs.as_ptr(),
+                ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS,
+                &mut simulation_outputs,
             );
+            simulation_outputs.reflections.reverbTimes
         }
     }
 }
@@ -334,12 +1002,21 @@ impl Clone for Source {
 
         Self {
             inner: self.inner,
-            inputs: self.inputs.clone(),
+            state: self.state.clone(),
+            gain: self.gain.clone(),
             simulator: self.simulator.clone(),
         }
     }
 }
 
+impl std::fmt::Debug for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Source")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl Drop for Source {
     fn drop(&mut self) {
         unsafe {
@@ -352,24 +1029,219 @@ unsafe impl Send for Source {}
 
 unsafe impl Sync for Source {}
 
+pub(crate) struct SourceState {
+    pub(crate) inputs: ffi::IPLSimulationInputs,
+    pub(crate) transmission_type: TransmissionType,
+}
+
+unsafe impl Send for SourceState {}
+
+/// Pending changes to the simulation inputs of a source, see
+/// [`Source::configure`].
+pub struct SourceInputs<'a> {
+    inner: &'a mut ffi::IPLSimulationInputs,
+    transmission_type: &'a mut TransmissionType,
+}
+
+impl SourceInputs<'_> {
+    /// The position and orientation of the source.
+    pub fn set_source(&mut self, source: Orientation) {
+        self.inner.source = source.into();
+    }
+
+    /// The orientation of the source, used for directivity, keeping its
+    /// position.
+    pub fn set_directivity_orientation(&mut self, rotation: Quat) {
+        self.inner.source = Orientation {
+            translation: self.inner.source.origin.into(),
+            rotation,
+        }
+        .into();
+    }
+
+    /// Apply frequency-independent distance attenuation.
+    pub fn set_distance_attenuation(
+        &mut self,
+        distance_attenuation_model: DistanceAttenuationModel,
+    ) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |=
+            ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DISTANCEATTENUATION;
+        self.inner.distanceAttenuationModel = distance_attenuation_model.into();
+    }
+
+    /// Apply frequency-dependent air absorption as a function of distance.
+    pub fn set_air_absorption(&mut self, air_absorption_model: AirAbsorptionModel) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |=
+            ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_AIRABSORPTION;
+        self.inner.airAbsorptionModel = air_absorption_model.into();
+    }
+
+    /// Apply attenuation due to source directivity pattern.
+    pub fn set_directivity(&mut self, directivity: Directivity) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |=
+            ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DIRECTIVITY;
+        self.inner.directivity = directivity.into();
+    }
+
+    /// Apply occlusion.
+    pub fn set_occlusion(&mut self) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_OCCLUSION;
+        self.inner.occlusionType = ffi::IPLOcclusionType_IPL_OCCLUSIONTYPE_RAYCAST;
+    }
+
+    /// Apply volumetric occlusion, the source is modeled as a sphere with the
+    /// given radius, and `samples` points within the sphere are tested for
+    /// visibility from the listener. `samples` must not exceed the maximum
+    /// number of occlusion samples the simulator was created with.
+    pub fn set_volumetric_occlusion(&mut self, radius: f32, samples: u32) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |= ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_OCCLUSION;
+        self.inner.occlusionType = ffi::IPLOcclusionType_IPL_OCCLUSIONTYPE_VOLUMETRIC;
+        self.inner.occlusionRadius = radius;
+        self.inner.numOcclusionSamples = samples as i32;
+    }
+
+    /// Apply transmission along with occlusion, see
+    /// [`Source::set_transmission`].
+    pub fn set_transmission(&mut self, maximum_surfaces: u32, transmission_type: TransmissionType) {
+        debug_assert!(maximum_surfaces > 0);
+
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_DIRECT;
+        self.inner.directFlags |=
+            ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_TRANSMISSION;
+        self.inner.numTransmissionRays = maximum_surfaces as i32;
+        *self.transmission_type = transmission_type;
+    }
+
+    pub fn set_reflections(&mut self) {
+        self.inner.flags |= ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS;
+    }
+
+    /// Use baked data identified by `identifier` instead of simulating in real
+    /// time, the data is looked up in the probe batches added to the
+    /// simulator.
+    pub fn set_baked_data_identifier(&mut self, identifier: BakedDataIdentifier) {
+        self.inner.baked = ffi::IPLbool_IPL_TRUE;
+        self.inner.bakedDataIdentifier = identifier.into();
+    }
+
+    /// Stop applying distance attenuation.
+    pub fn clear_distance_attenuation(&mut self) {
+        self.inner.directFlags &=
+            !ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DISTANCEATTENUATION;
+    }
+
+    /// Stop applying air absorption.
+    pub fn clear_air_absorption(&mut self) {
+        self.inner.directFlags &=
+            !ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_AIRABSORPTION;
+    }
+
+    /// Stop applying attenuation due to source directivity.
+    pub fn clear_directivity(&mut self) {
+        self.inner.directFlags &=
+            !ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_DIRECTIVITY;
+    }
+
+    /// Stop applying occlusion, and transmission, which is only applied along
+    /// with occlusion.
+    pub fn clear_occlusion(&mut self) {
+        self.inner.directFlags &=
+            !(ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_OCCLUSION
+                | ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_TRANSMISSION);
+    }
+
+    /// Stop applying transmission, keeping occlusion.
+    pub fn clear_transmission(&mut self) {
+        self.inner.directFlags &=
+            !ffi::IPLDirectSimulationFlags_IPL_DIRECTSIMULATIONFLAGS_TRANSMISSION;
+    }
+
+    pub fn clear_reflections(&mut self) {
+        self.inner.flags &= !ffi::IPLSimulationFlags_IPL_SIMULATIONFLAGS_REFLECTIONS;
+    }
+}
+
+/// Modes of applying transmission effects.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TransmissionType {
+    /// Transmission is modeled as a single attenuation factor, the average of
+    /// the coefficients of all three frequency bands.
+    #[default]
+    FrequencyIndependent,
+
+    /// Transmission is modeled as a separate attenuation factor for each of the
+    /// three frequency bands.
+    FrequencyDependent,
+}
+
+impl From<TransmissionType> for ffi::IPLTransmissionType {
+    fn from(value: TransmissionType) -> Self {
+        match value {
+            TransmissionType::FrequencyIndependent => {
+                ffi::IPLTransmissionType_IPL_TRANSMISSIONTYPE_FREQINDEPENDENT
+            }
+            TransmissionType::FrequencyDependent => {
+                ffi::IPLTransmissionType_IPL_TRANSMISSIONTYPE_FREQDEPENDENT
+            }
+        }
+    }
+}
+
 /// A distance attenuation model that can be used for modeling attenuation of
 /// sound over distance. Can be used with both direct and indirect sound
 /// propagation.
 #[derive(Default)]
 pub enum DistanceAttenuationModel {
+    /// The default distance attenuation model. This is an inverse distance
+    /// falloff, with all sounds within 1 meter of the listener rendered
+    /// without distance attenuation.
     #[default]
     Default,
-    InverseDistance(f32),
+
+    /// An inverse distance falloff.
+    InverseDistance {
+        /// Reference distance in meters, sounds closer than this distance are
+        /// rendered without distance attenuation.
+        min_distance: f32,
+    },
+
+    /// An arbitrary distance falloff function, mapping the distance in meters
+    /// to the attenuation factor.
     Custom(Box<dyn Fn(f32) -> f32>),
 }
 
+impl DistanceAttenuationModel {
+    /// An inverse distance falloff, which cuts off completely at
+    /// `max_distance`, so that sources beyond it are inaudible.
+    ///
+    /// Sounds at or closer than `min_distance` are rendered without distance
+    /// attenuation, including sounds at the listener position when
+    /// `min_distance` is zero.
+    pub fn inverse_distance_clamped(min_distance: f32, max_distance: f32) -> Self {
+        Self::Custom(Box::new(move |distance| {
+            if distance >= max_distance {
+                0.0
+            } else if distance <= min_distance {
+                1.0
+            } else {
+                min_distance / distance
+            }
+        }))
+    }
+}
+
 impl From<DistanceAttenuationModel> for ffi::IPLDistanceAttenuationModel {
     fn from(value: DistanceAttenuationModel) -> Self {
         unsafe extern "C" fn callback_trampoline(
             distance: ffi::IPLfloat32,
             user_data: *mut std::os::raw::c_void,
         ) -> ffi::IPLfloat32 {
-            let callback: &mut Box<dyn Fn(f32) -> f32> = unsafe { std::mem::transmute(user_data) };
+            let callback = unsafe { &*(user_data as *const Box<dyn Fn(f32) -> f32>) };
             callback(distance)
         }
 
@@ -381,10 +1253,10 @@ impl From<DistanceAttenuationModel> for ffi::IPLDistanceAttenuationModel {
                 userData: std::ptr::null_mut(),
                 dirty: ffi::IPLbool_IPL_FALSE,
             },
-            DistanceAttenuationModel::InverseDistance(distance) => Self {
+            DistanceAttenuationModel::InverseDistance { min_distance } => Self {
                 type_:
                     ffi::IPLDistanceAttenuationModelType_IPL_DISTANCEATTENUATIONTYPE_INVERSEDISTANCE,
-                minDistance: distance,
+                minDistance: min_distance,
                 callback: None,
                 userData: std::ptr::null_mut(),
                 dirty: ffi::IPLbool_IPL_FALSE,
@@ -417,8 +1289,7 @@ impl From<AirAbsorptionModel> for ffi::IPLAirAbsorptionModel {
             band: ffi::IPLint32,
             user_data: *mut std::os::raw::c_void,
         ) -> ffi::IPLfloat32 {
-            let callback: &mut Box<dyn Fn(f32, u8) -> f32> =
-                unsafe { std::mem::transmute(user_data) };
+            let callback = unsafe { &*(user_data as *const Box<dyn Fn(f32, u8) -> f32>) };
             callback(distance, band as u8)
         }
 
@@ -469,7 +1340,7 @@ impl From<Directivity> for ffi::IPLDirectivity {
             direction: ffi::IPLVector3,
             user_data: *mut std::os::raw::c_void,
         ) -> ffi::IPLfloat32 {
-            let callback: &mut Box<dyn Fn(Vec3) -> f32> = unsafe { std::mem::transmute(user_data) };
+            let callback = unsafe { &*(user_data as *const Box<dyn Fn(Vec3) -> f32>) };
             callback(direction.into())
         }
 
@@ -489,3 +1360,88 @@ impl From<Directivity> for ffi::IPLDirectivity {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_distance_model_to_ffi() {
+        let model: ffi::IPLDistanceAttenuationModel =
+            DistanceAttenuationModel::InverseDistance { min_distance: 2.0 }.into();
+        assert_eq!(
+            model.type_,
+            ffi::IPLDistanceAttenuationModelType_IPL_DISTANCEATTENUATIONTYPE_INVERSEDISTANCE
+        );
+        assert_eq!(model.minDistance, 2.0);
+        assert!(model.callback.is_none());
+    }
+
+    #[test]
+    fn inverse_distance_clamped_cuts_off() {
+        let DistanceAttenuationModel::Custom(callback) =
+            DistanceAttenuationModel::inverse_distance_clamped(1.0, 10.0)
+        else {
+            panic!("expected a custom model");
+        };
+        assert_eq!(callback(0.5), 1.0);
+        assert_eq!(callback(1.0), 1.0);
+        assert_eq!(callback(4.0), 0.25);
+        assert_eq!(callback(10.0), 0.0);
+        assert_eq!(callback(20.0), 0.0);
+
+        let DistanceAttenuationModel::Custom(callback) =
+            DistanceAttenuationModel::inverse_distance_clamped(0.0, 10.0)
+        else {
+            panic!("expected a custom model");
+        };
+        assert_eq!(callback(0.0), 1.0);
+        assert_eq!(callback(4.0), 0.0);
+    }
+
+    #[test]
+    fn set_reflections_reports_the_exceeding_parameter() {
+        let context = Context::new().unwrap();
+        let mut simulator = context
+            .create_simulator_with_settings(SimulationSettings::with_reflections(48000, 256))
+            .unwrap();
+
+        assert!(matches!(
+            simulator.set_reflections(4096, 16, 1.0, 2, 1.0),
+            Err(Error::ExceedsMaximum {
+                parameter: "order",
+                value: 2.0,
+                maximum: 1.0,
+            })
+        ));
+        assert!(matches!(
+            simulator.set_reflections(4096, 16, 2.0, 1, 1.0),
+            Err(Error::ExceedsMaximum {
+                parameter: "duration",
+                value: 2.0,
+                maximum: 1.0,
+            })
+        ));
+    }
+
+    #[test]
+    fn reverb_source_follows_listener() {
+        let context = Context::new().unwrap();
+        let mut simulator = context
+            .create_simulator_with_settings(SimulationSettings::with_reflections(48000, 256))
+            .unwrap();
+        let mut reverb_source = simulator.create_reverb_source().unwrap();
+
+        simulator.set_listener_pose(Vec3::new(1.0, 2.0, 3.0), Quat::IDENTITY);
+        let origin = reverb_source.state.lock().unwrap().inputs.source.origin;
+        assert_eq!((origin.x, origin.y, origin.z), (1.0, 2.0, 3.0));
+
+        // Configuring the source resubmits its inputs, which must not move it
+        // back to where it was created.
+        reverb_source.set_reflections();
+        let state = reverb_source.state.lock().unwrap();
+        let origin = state.inputs.source.origin;
+        assert_eq!((origin.x, origin.y, origin.z), (1.0, 2.0, 3.0));
+        assert_eq!(state.inputs.reverbScale, [1.0, 1.0, 1.0]);
+    }
+}
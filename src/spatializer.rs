@@ -0,0 +1,197 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use glam::Vec3;
+use rodio::{
+    cpal::FromSample,
+    dynamic_mixer::{self, DynamicMixer, DynamicMixerController},
+    source::{UniformSourceIterator, Zero},
+    Sample, Source,
+};
+
+use crate::{
+    context::Context,
+    effect::{BinauralEffectParams, HrtfInterpolation},
+    error::Result,
+    geometry::Orientation,
+    hrtf::Hrtf,
+    simulation::{self, AirAbsorptionModel, DistanceAttenuationModel, Simulator},
+    transform::transform,
+};
+
+/// Renders sources binaurally for a single listener, applying distance
+/// attenuation and air absorption, and mixes them into a single stereo output.
+///
+/// This covers the common case of spatializing a few sounds without having to
+/// wire up the simulator and effects manually, for anything else, e.g.
+/// reflections or Ambisonics, the effects have to be used directly.
+pub struct Spatializer {
+    context: Context,
+    simulator: Simulator,
+    hrtf: Hrtf,
+
+    sampling_rate: u32,
+    frame_size: u32,
+
+    listener: Arc<Mutex<Orientation>>,
+    interpolation: Arc<Mutex<HrtfInterpolation>>,
+
+    mixer_controller: Arc<DynamicMixerController<f32>>,
+    mixer: Option<DynamicMixer<f32>>,
+}
+
+impl Spatializer {
+    /// Creates a spatializer using the default HRTF.
+    pub fn new(context: &Context, sampling_rate: u32, frame_size: u32) -> Result<Self> {
+        let (mixer_controller, mixer) = dynamic_mixer::mixer(2, sampling_rate);
+        // Keeps the output playing while no sources are added.
+        mixer_controller.add(Zero::new(2, sampling_rate));
+
+        Ok(Self {
+            context: context.clone(),
+            simulator: context.create_simulator(sampling_rate, frame_size)?,
+            hrtf: context.default_hrtf(sampling_rate, frame_size)?,
+            sampling_rate,
+            frame_size,
+            listener: Default::default(),
+            interpolation: Arc::new(Mutex::new(HrtfInterpolation::Bilinear)),
+            mixer_controller,
+            mixer: Some(mixer),
+        })
+    }
+
+    /// The position and orientation of the listener.
+    pub fn set_listener(&mut self, listener: Orientation) {
+        self.simulator.set_listener(listener);
+        *self.listener.lock().unwrap() = listener;
+    }
+
+    /// The HRTF interpolation used for all sources, defaults to
+    /// [`HrtfInterpolation::Bilinear`]. [`HrtfInterpolation::Nearest`] trades
+    /// quality for less CPU usage.
+    pub fn set_hrtf_interpolation(&mut self, interpolation: HrtfInterpolation) {
+        *self.interpolation.lock().unwrap() = interpolation;
+    }
+
+    /// Adds a source at the given position, which is played until it ends or
+    /// the returned handle is dropped.
+    ///
+    /// The source is downmixed to mono and resampled to the sampling rate of
+    /// the spatializer.
+    pub fn add_source<I>(&mut self, input: I, position: Vec3) -> Result<SpatializerSource>
+    where
+        I: Source + Send + 'static,
+        I::Item: Sample + Send,
+        f32: FromSample<I::Item>,
+    {
+        let mut source = self.simulator.create_source_active(true)?;
+        source.configure(|inputs| {
+            inputs.set_source(Orientation {
+                translation: position,
+                rotation: Default::default(),
+            });
+            inputs.set_distance_attenuation(DistanceAttenuationModel::Default);
+            inputs.set_air_absorption(AirAbsorptionModel::Default);
+        });
+        self.simulator.commit();
+
+        let mut effects = self.context.create_binaural_effect_set(
+            &self.hrtf,
+            self.sampling_rate,
+            self.frame_size,
+        )?;
+        let position = Arc::new(Mutex::new(position));
+        let stopped = Arc::new(AtomicBool::new(false));
+        {
+            let context = self.context.clone();
+            let listener = self.listener.clone();
+            let interpolation = self.interpolation.clone();
+            let position = position.clone();
+            let source = source.clone();
+            let stopped = stopped.clone();
+
+            self.mixer_controller.add(
+                transform(
+                    UniformSourceIterator::new(input, 1, self.sampling_rate),
+                    move |in_, out| {
+                        effects.apply(
+                            &source,
+                            BinauralEffectParams {
+                                direction: context.calculate_relative_direction(
+                                    *position.lock().unwrap(),
+                                    *listener.lock().unwrap(),
+                                ),
+                                interpolation: *interpolation.lock().unwrap(),
+                                spatial_blend: 1.0,
+                            },
+                            in_,
+                            out,
+                        );
+                    },
+                    2,
+                    self.frame_size,
+                )
+                .stoppable()
+                .periodic_access(Duration::from_millis(5), move |transform| {
+                    if stopped.load(Ordering::Relaxed) {
+                        transform.stop();
+                    }
+                }),
+            );
+        }
+
+        Ok(SpatializerSource {
+            source,
+            position,
+            stopped,
+        })
+    }
+
+    /// Runs the direct simulation for all sources, should be called after
+    /// moving the listener or sources, e.g. once per game frame. Nothing is
+    /// simulated if neither the listener nor any source changed.
+    pub fn update(&self) {
+        if self.simulator.needs_direct_run() {
+            self.simulator.run_direct();
+        }
+    }
+
+    /// Takes the stereo output of the spatializer, which can be played on a
+    /// `rodio` output stream. Returns `None` if the output was already taken.
+    pub fn output(&mut self) -> Option<DynamicMixer<f32>> {
+        self.mixer.take()
+    }
+}
+
+/// Handle to a source added to a [`Spatializer`], dropping it stops the
+/// source and removes it from the simulation.
+pub struct SpatializerSource {
+    source: simulation::Source,
+    position: Arc<Mutex<Vec3>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl SpatializerSource {
+    /// Moves the source, the attenuation is updated with the next
+    /// [`Spatializer::update`].
+    pub fn set_position(&mut self, position: Vec3) {
+        self.source.set_source(Orientation {
+            translation: position,
+            rotation: Default::default(),
+        });
+        *self.position.lock().unwrap() = position;
+    }
+}
+
+impl Drop for SpatializerSource {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        self.source.set_active(false);
+        self.source.simulator.commit_deferred();
+    }
+}
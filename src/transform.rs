@@ -1,6 +1,9 @@
 use std::{
     cmp, mem,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
     time::Duration,
 };
 
@@ -22,6 +25,7 @@ where
     let input_buffer = Buffer::new(input.channels(), frame_size);
     let output_buffer = Buffer::new(output_channels, frame_size);
 
+    let sample_rate = input.sample_rate();
     let total_duration = input.total_duration();
 
     let mut transform = Transform {
@@ -33,12 +37,71 @@ where
             next: Mutex::new(Arc::new(Frame::Input(Mutex::new(Some(input))))),
         })),
         position_in_frame: 0,
+        sample_rate,
         total_duration,
+        peak_meter: Default::default(),
     };
     transform.next_frame();
     transform
 }
 
+/// Feeds the same input to two functions and sums their outputs, so that a
+/// single decoded source can drive two effect chains, e.g. a direct and a
+/// reflection path.
+pub fn tee<A, B>(
+    mut first: A,
+    mut second: B,
+    output_channels: u16,
+    frame_size: u32,
+) -> impl FnMut(&Buffer, &mut Buffer)
+where
+    A: FnMut(&Buffer, &mut Buffer),
+    B: FnMut(&Buffer, &mut Buffer),
+{
+    let mut second_output_buffer = Buffer::new(output_channels, frame_size);
+
+    move |in_, out| {
+        first(in_, out);
+        second(in_, &mut second_output_buffer);
+
+        for (channel, second_channel) in out.data.iter_mut().zip(&second_output_buffer.data) {
+            for (sample, second_sample) in channel.iter_mut().zip(second_channel) {
+                *sample += second_sample;
+            }
+        }
+    }
+}
+
+/// Returns the sampling rate of the default output device, or `None` if there
+/// is none.
+///
+/// Running the effects at this sampling rate avoids resampling the output
+/// again before it is played.
+pub fn default_output_sampling_rate() -> Option<u32> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = rodio::cpal::default_host().default_output_device()?;
+    Some(device.default_output_config().ok()?.sample_rate().0)
+}
+
+/// Soft clips the output of a function, e.g. the final decode stage before the
+/// result is played, see [`Buffer::soft_clip`].
+pub fn soft_clip<F>(mut function: F) -> impl FnMut(&Buffer, &mut Buffer)
+where
+    F: FnMut(&Buffer, &mut Buffer),
+{
+    move |in_, out| {
+        function(in_, out);
+        out.soft_clip();
+    }
+}
+
+/// Applies a function operating on fixed-size [`Buffer`]s to a `rodio` source.
+///
+/// The transform doesn't own an output stream, it can be played on any
+/// `rodio::OutputStreamHandle`, e.g. one created for a specific output device
+/// with `rodio::OutputStream::try_from_device`, or added to a mixer. The
+/// `rodio::OutputStream` has to be kept alive for as long as audio is played.
 pub struct Transform<I, F>
 where
     I: Source<Item = f32>,
@@ -52,7 +115,28 @@ where
     current_frame: Arc<Frame<I>>,
     position_in_frame: usize,
 
+    sample_rate: u32,
     total_duration: Option<Duration>,
+
+    peak_meter: PeakMeter,
+}
+
+/// Shared handle to the peak of the most recently processed output frame of a
+/// [`Transform`], which stays accessible after the transform has been handed
+/// over to a mixer or output stream.
+#[derive(Clone, Default)]
+pub struct PeakMeter(Arc<AtomicU32>);
+
+impl PeakMeter {
+    /// The maximum absolute sample value of the most recently processed output
+    /// frame.
+    pub fn peak(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    fn set_peak(&self, peak: f32) {
+        self.0.store(peak.to_bits(), Ordering::Relaxed);
+    }
 }
 
 enum Frame<I>
@@ -107,6 +191,30 @@ where
     I: Source<Item = f32>,
     F: FnMut(&Buffer, &mut Buffer),
 {
+    /// The maximum absolute sample value of the most recently processed output
+    /// frame, useful for debugging silent output.
+    pub fn last_frame_peak(&self) -> f32 {
+        self.peak_meter.peak()
+    }
+
+    /// Returns a handle to the peak of the most recently processed output
+    /// frame.
+    pub fn peak_meter(&self) -> PeakMeter {
+        self.peak_meter.clone()
+    }
+
+    /// Returns the latency (in samples per channel) added by the transform,
+    /// which is one frame, as a whole frame of input is read before the
+    /// function is applied.
+    ///
+    /// Steam Audio effects process each frame without buffering beyond it, the
+    /// latency of an effect chain within a single transform is therefore the
+    /// frame size. Nested transforms, e.g. decoding the output of a mixer of
+    /// transformed sources, add one frame each.
+    pub fn latency_samples(&self) -> u32 {
+        self.output_buffer.samples()
+    }
+
     fn next_frame(&mut self) {
         let next_frame = {
             let mut next_frame_ptr = match &*self.current_frame {
@@ -151,6 +259,13 @@ where
                         }
                     };
                     (self.function)(&self.input_buffer, &mut self.output_buffer);
+                    self.peak_meter.set_peak(
+                        self.output_buffer
+                            .data
+                            .iter()
+                            .flatten()
+                            .fold(0.0, |peak, sample| sample.abs().max(peak)),
+                    );
                     next_frame
                 }
             };
@@ -218,7 +333,7 @@ where
 
     #[inline]
     fn sample_rate(&self) -> u32 {
-        44100 // TODO
+        self.sample_rate
     }
 
     #[inline]
@@ -226,3 +341,274 @@ where
         self.total_duration
     }
 }
+
+/// Creates an endless source from a function, which is called once per frame
+/// to fill in the interleaved samples of all channels.
+///
+/// This is useful to generate synthetic signals like tones or noise without
+/// decoding any audio asset.
+#[inline]
+pub fn from_fn<F>(channels: u16, sample_rate: u32, function: F) -> FromFn<F>
+where
+    F: FnMut(&mut [f32]),
+{
+    FromFn {
+        function,
+        frame: vec![0.0; channels as usize],
+        position_in_frame: channels as usize,
+        sample_rate,
+    }
+}
+
+/// An endless source generated by a function, see [`from_fn`].
+pub struct FromFn<F>
+where
+    F: FnMut(&mut [f32]),
+{
+    function: F,
+
+    frame: Vec<f32>,
+    position_in_frame: usize,
+
+    sample_rate: u32,
+}
+
+impl<F> Iterator for FromFn<F>
+where
+    F: FnMut(&mut [f32]),
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.position_in_frame >= self.frame.len() {
+            (self.function)(&mut self.frame);
+            self.position_in_frame = 0;
+        }
+
+        let current_sample = self.frame[self.position_in_frame];
+        self.position_in_frame += 1;
+        Some(current_sample)
+    }
+}
+
+impl<F> Source for FromFn<F>
+where
+    F: FnMut(&mut [f32]),
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.frame.len() as u16
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Resamples a source by a shared factor using linear interpolation, e.g. to
+/// apply a Doppler shift. A factor greater than 1.0 raises the pitch, a factor
+/// less than 1.0 lowers it.
+///
+/// The factor is read once per frame, so it can be changed from another thread
+/// while the source is playing, see [`doppler_factor`] for calculating it.
+///
+/// The shift has to be applied per source, before the source is passed to the
+/// direct, reflection or Ambisonics encode effects, so that the direct sound
+/// and its reflections are shifted alike. Ambisonics buses mix sources moving
+/// at different velocities, and therefore can't be shifted as a whole.
+///
+/// [`doppler_factor`]: crate::geometry::doppler_factor
+#[inline]
+pub fn doppler<I>(mut input: I, factor: Arc<Mutex<f32>>) -> Doppler<I>
+where
+    I: Source<Item = f32>,
+{
+    let channels = input.channels() as usize;
+    let current_frame = input.by_ref().take(channels).collect::<Vec<_>>();
+    let next_frame = input.by_ref().take(channels).collect::<Vec<_>>();
+
+    Doppler {
+        input,
+        factor,
+        current_frame,
+        next_frame,
+        position_between_frames: 0.0,
+        position_in_frame: 0,
+        output_frame: vec![0.0; channels],
+    }
+}
+
+pub struct Doppler<I>
+where
+    I: Source<Item = f32>,
+{
+    input: I,
+    factor: Arc<Mutex<f32>>,
+
+    current_frame: Vec<f32>,
+    next_frame: Vec<f32>,
+    position_between_frames: f32,
+
+    output_frame: Vec<f32>,
+    position_in_frame: usize,
+}
+
+impl<I> Doppler<I>
+where
+    I: Source<Item = f32>,
+{
+    fn next_frame(&mut self) -> Option<()> {
+        if self.next_frame.len() != self.current_frame.len() || self.current_frame.is_empty() {
+            return None;
+        }
+
+        for (output, (current, next)) in self
+            .output_frame
+            .iter_mut()
+            .zip(self.current_frame.iter().zip(&self.next_frame))
+        {
+            *output = current + (next - current) * self.position_between_frames;
+        }
+        self.position_in_frame = 0;
+
+        self.position_between_frames += *self.factor.lock().unwrap();
+        while self.position_between_frames >= 1.0 {
+            self.position_between_frames -= 1.0;
+
+            mem::swap(&mut self.current_frame, &mut self.next_frame);
+            self.next_frame.clear();
+            let channels = self.current_frame.len();
+            self.next_frame.extend(self.input.by_ref().take(channels));
+        }
+
+        Some(())
+    }
+}
+
+impl<I> Iterator for Doppler<I>
+where
+    I: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.position_in_frame == 0 {
+            self.next_frame()?;
+        }
+
+        let current_sample = self.output_frame[self.position_in_frame];
+        self.position_in_frame = (self.position_in_frame + 1) % self.output_frame.len();
+        Some(current_sample)
+    }
+}
+
+impl<I> Source for Doppler<I>
+where
+    I: Source<Item = f32>,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.output_frame.len() as u16
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.input.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rodio::source::SineWave;
+
+    use super::*;
+
+    #[test]
+    fn drop_long_frame_chain() {
+        // With a frame size of 1, each sample of the mono input is a frame of its
+        // own, holding on to the first frame keeps the whole chain alive.
+        let input = SineWave::new(440.0).take_duration(Duration::from_secs(3));
+        let mut transform = transform(
+            input,
+            |in_, out| out.data()[0].copy_from_slice(in_.channel(0)),
+            1,
+            1,
+        );
+        let first_frame = transform.current_frame.clone();
+
+        assert!(transform.by_ref().count() > 100_000);
+
+        drop(transform);
+        drop(first_frame);
+    }
+
+    #[test]
+    fn sample_rate_of_input() {
+        let input = SineWave::new(440.0).take_duration(Duration::from_millis(10));
+        assert_eq!(input.sample_rate(), 48000);
+
+        let transform = transform(input, |_, _| {}, 2, 64);
+        assert_eq!(transform.sample_rate(), 48000);
+    }
+
+    #[test]
+    fn from_fn_through_transform() {
+        let input = || {
+            let mut index = 0.0;
+            from_fn(2, 1000, move |frame| {
+                frame[0] = index;
+                frame[1] = -index;
+                index += 1.0;
+            })
+            .take_duration(Duration::from_millis(128))
+        };
+        // Only whole frames of the input are transformed, a trailing partial
+        // frame is dropped.
+        let frames = input().count() / 2;
+        assert!(frames > 64);
+
+        let transform = transform(
+            input(),
+            |in_, out| {
+                for channel in 0..2 {
+                    out.data()[channel].copy_from_slice(in_.channel(channel));
+                }
+            },
+            2,
+            64,
+        );
+        assert_eq!(transform.channels(), 2);
+        assert_eq!(transform.sample_rate(), 1000);
+
+        let samples = transform.collect::<Vec<_>>();
+        assert_eq!(samples.len(), 2 * frames);
+        for (index, frame) in samples.chunks(2).enumerate() {
+            assert_eq!(frame, [index as f32, -(index as f32)]);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{self, Seek, SeekFrom, Write};
+
+use crate::buffer::Buffer;
+
+/// Writes buffers to a 32-bit floating point WAV file with any number of
+/// channels, e.g. to record an Ambisonics bus for use in other tools.
+///
+/// Ambisonics buffers are written in ACN channel order, and should be converted
+/// with [`Buffer::n3d_to_sn3d`] before writing them, to get an AmbiX file.
+pub struct WavWriter<W: Write + Seek> {
+    writer: W,
+    channels: u16,
+    data_size: u32,
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Writes the header, the sizes are filled in by [`WavWriter::finish`].
+    pub fn new(mut writer: W, channels: u16, sampling_rate: u32) -> io::Result<Self> {
+        let block_align = channels * 4;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+
+        // WAVE_FORMAT_EXTENSIBLE, as plain formats are ambiguous for more than
+        // two channels
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&40u32.to_le_bytes())?;
+        writer.write_all(&0xFFFEu16.to_le_bytes())?;
+        writer.write_all(&channels.to_le_bytes())?;
+        writer.write_all(&sampling_rate.to_le_bytes())?;
+        writer.write_all(&(sampling_rate * block_align as u32).to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&32u16.to_le_bytes())?;
+        writer.write_all(&22u16.to_le_bytes())?;
+        writer.write_all(&32u16.to_le_bytes())?;
+        // No speaker positions, the channels are Ambisonics components
+        writer.write_all(&0u32.to_le_bytes())?;
+        // KSDATAFORMAT_SUBTYPE_IEEE_FLOAT
+        writer.write_all(&[
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38,
+            0x9B, 0x71,
+        ])?;
+
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        Ok(Self {
+            writer,
+            channels,
+            data_size: 0,
+        })
+    }
+
+    /// Appends the samples of the buffer, which must have as many channels as
+    /// the file.
+    pub fn write(&mut self, buffer: &Buffer) -> io::Result<()> {
+        assert_eq!(buffer.channels(), self.channels);
+
+        for frame in 0..buffer.samples() as usize {
+            for channel in buffer.channels_iter() {
+                self.writer.write_all(&channel[frame].to_le_bytes())?;
+            }
+        }
+        self.data_size += buffer.samples() * self.channels as u32 * 4;
+
+        Ok(())
+    }
+
+    /// Fills in the sizes in the header and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.seek(SeekFrom::Start(4))?;
+        self.writer
+            .write_all(&(4 + 8 + 40 + 8 + self.data_size).to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(4 + 4 + 4 + 8 + 40 + 4))?;
+        self.writer.write_all(&self.data_size.to_le_bytes())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}